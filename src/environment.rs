@@ -3,13 +3,42 @@ use crate::layout::{Alignment, LayoutDirection};
 pub trait LayoutEnvironment {
     fn layout_direction(&self) -> LayoutDirection;
     fn alignment(&self) -> Alignment;
+
+    /// The value of type `T` set by the nearest
+    /// [`environment`](crate::view::LayoutExtensions::environment) modifier, if any.
+    ///
+    /// The type of the value is its key, so wrap values in a newtype to distinguish them.
+    fn value<T: 'static>(&self) -> Option<&T> {
+        None
+    }
 }
 
 pub trait RenderEnvironment: LayoutEnvironment {
     type Color;
+    /// The resolved foreground color, inherited from the nearest `foreground_color` modifier.
+    ///
+    /// Custom views can use this to draw in the same color as the surrounding content.
     fn foreground_color(&self) -> Self::Color;
 }
 
+/// The built-in layout values of an environment, which can be overridden for a subtree with
+/// [`LayoutExtensions::environment_override`](crate::view::LayoutExtensions::environment_override)
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+pub struct LayoutValues {
+    pub layout_direction: LayoutDirection,
+    pub alignment: Alignment,
+}
+
+impl LayoutValues {
+    /// Captures the current values of an environment
+    pub fn new(environment: &impl LayoutEnvironment) -> Self {
+        Self {
+            layout_direction: environment.layout_direction(),
+            alignment: environment.alignment(),
+        }
+    }
+}
+
 pub struct DefaultEnvironment<Color> {
     foreground_color: Color,
 }
@@ -1,5 +1,9 @@
 use crate::{primitives::Point, render_target::CharacterRenderTarget};
 
+mod cached_metrics;
+
+pub use cached_metrics::CachedMetrics;
+
 /// A font that renders individual characters at a time.
 /// Multi-character graphemes are not supported, making
 /// this primarily useful for embedded devices.
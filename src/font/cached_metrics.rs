@@ -0,0 +1,94 @@
+use core::cell::RefCell;
+
+use crate::{primitives::Point, render_target::CharacterRenderTarget};
+
+use super::{CharacterFont, FontLayout};
+
+/// Remembers the widths of the `N` most recently measured characters, so fonts that are
+/// expensive to measure are not queried again for every layout pass.
+///
+/// Line height and baseline are passed through to the wrapped font. Rendering is delegated to
+/// the wrapped font.
+pub struct CachedMetrics<F, const N: usize> {
+    font: F,
+    widths: RefCell<heapless::Vec<(char, u16), N>>,
+}
+
+impl<F, const N: usize> CachedMetrics<F, N> {
+    pub fn new(font: F) -> Self {
+        Self {
+            font,
+            widths: RefCell::new(heapless::Vec::new()),
+        }
+    }
+
+    /// The wrapped font
+    pub fn font(&self) -> &F {
+        &self.font
+    }
+
+    /// Forgets all measured widths
+    pub fn clear(&self) {
+        self.widths.borrow_mut().clear();
+    }
+}
+
+impl<F: FontLayout, const N: usize> FontLayout for CachedMetrics<F, N> {
+    #[inline]
+    fn line_height(&self) -> u16 {
+        self.font.line_height()
+    }
+
+    /// Hits move to the front of the cache. On a miss, the least recently used width is evicted.
+    fn character_width(&self, character: char) -> u16 {
+        let mut widths = self.widths.borrow_mut();
+        if let Some(index) = widths.iter().position(|(c, _)| *c == character) {
+            let width = widths[index].1;
+            widths[..=index].rotate_right(1);
+            return width;
+        }
+        let width = self.font.character_width(character);
+        if N > 0 {
+            if widths.is_full() {
+                widths.pop();
+            }
+            _ = widths.insert(0, (character, width));
+        }
+        width
+    }
+
+    #[inline]
+    fn baseline(&self) -> u16 {
+        self.font.baseline()
+    }
+}
+
+impl<C: Copy, F: CharacterFont<C>, const N: usize> CharacterFont<C> for CachedMetrics<F, N> {
+    fn render_iter_solid<T, I>(&self, target: &mut T, origin: Point, color: C, characters: I)
+    where
+        T: CharacterRenderTarget<Color = C>,
+        I: IntoIterator<Item = char>,
+    {
+        self.font
+            .render_iter_solid(target, origin, color, characters);
+    }
+
+    fn render_iter<T, I>(&self, target: &mut T, origin: Point, characters: I)
+    where
+        T: CharacterRenderTarget<Color = C>,
+        I: IntoIterator<Item = (char, C)>,
+    {
+        self.font.render_iter(target, origin, characters);
+    }
+}
+
+#[cfg(feature = "embedded-graphics")]
+impl<C, F: super::PixelFont<C>, const N: usize> super::PixelFont<C> for CachedMetrics<F, N> {
+    fn render_iter<T, I>(&self, target: &mut T, origin: Point, color: C, characters: I)
+    where
+        T: embedded_graphics::draw_target::DrawTarget<Color = C>,
+        I: IntoIterator<Item = char>,
+    {
+        self.font.render_iter(target, origin, color, characters);
+    }
+}
@@ -46,18 +46,44 @@ pub enum VerticalAlignment {
     Center,
     /// Align the content to the end of the layout direction
     Bottom,
+    /// Align the baselines of the first line of text in each view.
+    ///
+    /// Only stacks that lay out views side by side honor this. Elsewhere it aligns to the top.
+    FirstTextBaseline,
+    /// Align the baselines of the last line of text in each view.
+    ///
+    /// Only stacks that lay out views side by side honor this. Elsewhere it aligns to the top.
+    LastTextBaseline,
 }
 
 impl VerticalAlignment {
     pub fn align(&self, available: i16, content: i16) -> i16 {
         match self {
-            VerticalAlignment::Top => 0,
+            VerticalAlignment::Top
+            | VerticalAlignment::FirstTextBaseline
+            | VerticalAlignment::LastTextBaseline => 0,
             VerticalAlignment::Center => (available - content) / 2,
             VerticalAlignment::Bottom => available - content,
         }
     }
 }
 
+/// What a stack does with children that do not fit in the space offered to it
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+pub enum OverflowBehavior {
+    /// Children which do not fit draw past the end of the stack
+    #[default]
+    Overflow,
+    /// Children are clipped to the bounds of the stack along its axis, cutting off the last ones
+    ClipLast,
+    /// Children are offered smaller sizes so they fit.
+    ///
+    /// Stacks already offer each child the smallest share of space they can, so a child that
+    /// still overflows has refused that offer. Such children cannot be compressed further, and
+    /// this currently behaves like [`OverflowBehavior::Overflow`].
+    Compress,
+}
+
 #[derive(Clone, PartialEq)]
 pub struct ResolvedLayout<C: Clone + PartialEq> {
     pub sublayouts: C,
@@ -72,4 +98,14 @@ pub trait Layout: Sized {
     fn priority(&self) -> i8 {
         0
     }
+    /// The distance from the top of the resolved layout to the baseline of the first line of
+    /// text, or None if the view does not contain text
+    fn first_baseline(&self, _layout: &ResolvedLayout<Self::Sublayout>) -> Option<u16> {
+        None
+    }
+    /// The distance from the top of the resolved layout to the baseline of the last line of
+    /// text, or None if the view does not contain text
+    fn last_baseline(&self, _layout: &ResolvedLayout<Self::Sublayout>) -> Option<u16> {
+        None
+    }
 }
@@ -1,5 +1,4 @@
 #![cfg_attr(not(feature = "std"), no_std)]
-#![feature(type_alias_impl_trait)]
 
 #[cfg(feature = "std")]
 extern crate std;
@@ -11,6 +10,8 @@ pub mod environment;
 pub mod font;
 pub mod layout;
 pub mod pixel;
+#[cfg(feature = "std")]
+pub mod preview;
 pub mod primitives;
 pub mod render;
 pub mod render_target;
@@ -0,0 +1,131 @@
+//! Helpers for showing the output of a view as text or pixels, for examples, doctests, and tests.
+
+use std::string::String;
+use std::vec;
+use std::vec::Vec;
+
+use crate::{
+    environment::DefaultEnvironment,
+    primitives::{Point, Size},
+    render::CharacterRender,
+    render_target::CharacterRenderTarget,
+};
+
+#[cfg(feature = "embedded-graphics")]
+use embedded_graphics::{pixelcolor::Rgb888, prelude::RgbColor as _};
+
+/// Lays out and renders a view into a text grid of the given size.
+///
+/// Rows are separated by newlines, and every row is padded with spaces to the full width.
+///
+/// ```
+/// use buoyant::font::BufferCharacterFont;
+/// use buoyant::preview::preview;
+/// use buoyant::primitives::Size;
+/// use buoyant::view::{Divider, Text, VStack};
+///
+/// let font = BufferCharacterFont;
+/// let view = VStack::new((Text::str("hello", &font), Divider::default()));
+///
+/// assert_eq!(preview(&view, Size::new(7, 3)), " hello \n-------\n       ");
+/// ```
+pub fn preview(view: &impl CharacterRender<()>, size: Size) -> String {
+    let env = DefaultEnvironment::new(());
+    let mut buffer = TextBuffer {
+        size,
+        text: vec![vec![' '; size.width as usize]; size.height as usize],
+    };
+    let layout = view.layout(size, &env);
+    view.render(&mut buffer, &layout, Point::zero(), &env);
+    buffer
+        .text
+        .iter()
+        .map(|row| row.iter().collect::<String>())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// A text buffer sized at runtime
+struct TextBuffer {
+    size: Size,
+    text: Vec<Vec<char>>,
+}
+
+impl CharacterRenderTarget for TextBuffer {
+    type Color = ();
+
+    fn size(&self) -> Size {
+        self.size
+    }
+
+    fn draw(&mut self, point: Point, character: char, _color: ()) {
+        if self.size.contains(point) {
+            self.text[point.y as usize][point.x as usize] = character;
+        }
+    }
+}
+
+/// Lays out and renders a view into a framebuffer of the given size.
+///
+/// The view is drawn in white over a black background. Each row of the framebuffer holds the
+/// colors of one row of pixels.
+///
+/// ```
+/// use buoyant::preview::preview_rgb;
+/// use buoyant::primitives::Size;
+/// use buoyant::view::{LayoutExtensions as _, Rectangle};
+/// use embedded_graphics::pixelcolor::Rgb888;
+/// use embedded_graphics::prelude::RgbColor as _;
+///
+/// let view = Rectangle.frame(Some(2), Some(1), None, None);
+/// let (on, off) = (Rgb888::WHITE, Rgb888::BLACK);
+///
+/// assert_eq!(
+///     preview_rgb(&view, Size::new(4, 3)),
+///     [[on, on, off, off], [off; 4], [off; 4]]
+/// );
+/// ```
+#[cfg(feature = "embedded-graphics")]
+pub fn preview_rgb(view: &impl crate::render::PixelRender<Rgb888>, size: Size) -> Vec<Vec<Rgb888>> {
+    let env = DefaultEnvironment::new(Rgb888::WHITE);
+    let mut framebuffer = Framebuffer {
+        size,
+        pixels: vec![vec![Rgb888::BLACK; size.width as usize]; size.height as usize],
+    };
+    let layout = view.layout(size, &env);
+    view.render(&mut framebuffer, &layout, Point::zero(), &env);
+    framebuffer.pixels
+}
+
+/// A pixel buffer sized at runtime
+#[cfg(feature = "embedded-graphics")]
+struct Framebuffer {
+    size: Size,
+    pixels: Vec<Vec<Rgb888>>,
+}
+
+#[cfg(feature = "embedded-graphics")]
+impl embedded_graphics_core::geometry::OriginDimensions for Framebuffer {
+    fn size(&self) -> embedded_graphics_core::geometry::Size {
+        self.size.into()
+    }
+}
+
+#[cfg(feature = "embedded-graphics")]
+impl embedded_graphics::draw_target::DrawTarget for Framebuffer {
+    type Color = Rgb888;
+    type Error = core::convert::Infallible;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = embedded_graphics::Pixel<Self::Color>>,
+    {
+        for embedded_graphics::Pixel(point, color) in pixels {
+            let point = Point::from(point);
+            if self.size.contains(point) {
+                self.pixels[point.y as usize][point.x as usize] = color;
+            }
+        }
+        Ok(())
+    }
+}
@@ -4,7 +4,9 @@ mod crossterm_render_target;
 #[cfg(feature = "crossterm")]
 pub use crossterm_render_target::CrosstermRenderTarget;
 
+mod dirty_tracking;
 mod fixed_text_buffer;
+pub use dirty_tracking::DirtyTracking;
 pub use fixed_text_buffer::FixedTextBuffer;
 
 use crate::primitives::{Point, Size};
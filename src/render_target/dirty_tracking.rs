@@ -0,0 +1,122 @@
+use core::cmp::{max, min};
+
+use crate::{
+    primitives::{Point, Size},
+    render_target::CharacterRenderTarget,
+};
+
+/// Records the area drawn to a render target, so only the changed region needs to be sent to
+/// the display.
+///
+/// The dirty region grows to cover every point drawn inside the target until it is reset,
+/// typically once per frame after flushing.
+pub struct DirtyTracking<T> {
+    target: T,
+    bounds: Option<(Point, Point)>,
+}
+
+impl<T> DirtyTracking<T> {
+    pub fn new(target: T) -> Self {
+        Self {
+            target,
+            bounds: None,
+        }
+    }
+
+    /// The origin and size of the smallest rectangle containing every point drawn since the
+    /// last reset, or None if nothing was drawn
+    pub fn dirty_region(&self) -> Option<(Point, Size)> {
+        self.bounds.map(|(top_left, bottom_right)| {
+            let size = Size::new(
+                (bottom_right.x - top_left.x) as u16 + 1,
+                (bottom_right.y - top_left.y) as u16 + 1,
+            );
+            (top_left, size)
+        })
+    }
+
+    /// Marks the whole target as clean
+    pub fn reset(&mut self) {
+        self.bounds = None;
+    }
+
+    pub fn target(&self) -> &T {
+        &self.target
+    }
+
+    pub fn target_mut(&mut self) -> &mut T {
+        &mut self.target
+    }
+
+    pub fn into_inner(self) -> T {
+        self.target
+    }
+}
+
+/// Expands the bounds to include a point
+fn include(bounds: &mut Option<(Point, Point)>, point: Point) {
+    *bounds = Some(match *bounds {
+        Some((top_left, bottom_right)) => (
+            Point::new(min(top_left.x, point.x), min(top_left.y, point.y)),
+            Point::new(max(bottom_right.x, point.x), max(bottom_right.y, point.y)),
+        ),
+        None => (point, point),
+    });
+}
+
+impl<T: CharacterRenderTarget> CharacterRenderTarget for DirtyTracking<T> {
+    type Color = T::Color;
+
+    fn size(&self) -> Size {
+        self.target.size()
+    }
+
+    fn draw(&mut self, point: Point, character: char, color: Self::Color) {
+        if self.target.size().contains(point) {
+            include(&mut self.bounds, point);
+        }
+        self.target.draw(point, character, color);
+    }
+}
+
+#[cfg(feature = "embedded-graphics")]
+mod embedded_graphics_impl {
+    use embedded_graphics::{draw_target::DrawTarget, primitives::Rectangle, Pixel};
+    use embedded_graphics_core::geometry::Dimensions;
+
+    use super::{include, DirtyTracking};
+
+    impl<T: Dimensions> Dimensions for DirtyTracking<T> {
+        fn bounding_box(&self) -> Rectangle {
+            self.target.bounding_box()
+        }
+    }
+
+    impl<T: DrawTarget> DrawTarget for DirtyTracking<T> {
+        type Color = T::Color;
+        type Error = T::Error;
+
+        fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+        where
+            I: IntoIterator<Item = Pixel<Self::Color>>,
+        {
+            let area = self.target.bounding_box();
+            let bounds = &mut self.bounds;
+            self.target
+                .draw_iter(pixels.into_iter().inspect(|Pixel(point, _)| {
+                    if area.contains(*point) {
+                        include(bounds, (*point).into());
+                    }
+                }))
+        }
+
+        fn fill_solid(&mut self, area: &Rectangle, color: Self::Color) -> Result<(), Self::Error> {
+            let drawn = area.intersection(&self.target.bounding_box());
+            if let Some(bottom_right) = drawn.bottom_right() {
+                include(&mut self.bounds, drawn.top_left.into());
+                include(&mut self.bounds, bottom_right.into());
+            }
+            self.target.fill_solid(area, color)
+        }
+    }
+}
@@ -2,8 +2,11 @@ mod conditional_view;
 mod divider;
 mod empty_view;
 mod foreach;
+mod geometry_reader;
+mod grid;
 mod hstack;
 mod modifier;
+mod section;
 mod shape;
 mod spacer;
 mod text;
@@ -13,17 +16,28 @@ mod zstack;
 pub use conditional_view::ConditionalView;
 pub use divider::Divider;
 pub use empty_view::EmptyView;
-pub use foreach::ForEach;
+pub use foreach::{ForEach, Separator};
+pub use geometry_reader::GeometryReader;
+pub use grid::{Grid, GridLayout, TrackSize};
 pub use hstack::HStack;
+pub use section::Section;
 pub use shape::style;
 pub use shape::Circle;
 pub use shape::Rectangle;
+pub use shape::RoundedRectangle;
+pub use shape::Shape;
 pub use spacer::Spacer;
-pub use text::{HorizontalTextAlignment, Text};
+pub use text::{
+    Highlighted, HorizontalTextAlignment, Text, TruncationMode, VerticalText, WhitespaceWrap,
+    WrappedLine,
+};
 pub use vstack::VStack;
 pub use zstack::ZStack;
 
-use modifier::{FixedFrame, FlexFrame, ForegroundStyle, Padding, Priority};
+use modifier::{
+    ClipShape, Clipped, EnvironmentOverride, EnvironmentValue, FixedFrame, FlexFrame,
+    ForegroundStyle, LayoutCache, Padding, Priority, When,
+};
 
 pub trait LayoutExtensions: Sized {
     fn padding(self, amount: u16) -> Padding<Self> {
@@ -45,6 +59,13 @@ pub trait LayoutExtensions: Sized {
         )
     }
 
+    /// Sizes the view to a fraction of the offer along each axis, where 1.0 is the full offer.
+    ///
+    /// Axes without a fraction take the size of the view.
+    fn frame_fraction(self, width: Option<f32>, height: Option<f32>) -> FixedFrame<Self> {
+        FixedFrame::fractional(self, width, height, None, None)
+    }
+
     fn flex_frame(
         self,
         min_width: Option<u16>,
@@ -65,9 +86,107 @@ pub trait LayoutExtensions: Sized {
         )
     }
 
+    /// Constrains only the width of the view, leaving its height exactly as the child resolves it
+    fn flex_width(
+        self,
+        min_width: Option<u16>,
+        max_width: Option<u16>,
+        alignment: Option<crate::layout::HorizontalAlignment>,
+    ) -> FlexFrame<Self> {
+        FlexFrame::new(self, min_width, max_width, None, None, alignment, None)
+    }
+
+    /// Constrains only the height of the view, leaving its width exactly as the child resolves it
+    fn flex_height(
+        self,
+        min_height: Option<u16>,
+        max_height: Option<u16>,
+        alignment: Option<crate::layout::VerticalAlignment>,
+    ) -> FlexFrame<Self> {
+        FlexFrame::new(self, None, None, min_height, max_height, None, alignment)
+    }
+
     fn priority(self, priority: u16) -> Priority<Self> {
         Priority::new(priority, self)
     }
+
+    /// Prevents the view from drawing outside of its bounds
+    fn clipped(self) -> Clipped<Self> {
+        Clipped::new(self, true, true)
+    }
+
+    /// Prevents the view from drawing outside of its bounds along the selected axes, allowing
+    /// content to overflow along the others
+    fn clipped_axis(self, horizontal: bool, vertical: bool) -> Clipped<Self> {
+        Clipped::new(self, horizontal, vertical)
+    }
+
+    /// Prevents the view from drawing outside of a shape fit to its bounds
+    fn clip_shape<S: Shape>(self, shape: S) -> ClipShape<Self, S> {
+        ClipShape::new(self, shape)
+    }
+
+    /// Clips the view to a rounded rectangle matching its bounds.
+    ///
+    /// Shapes can be given rounded corners directly with [`Rectangle::corner_radius`].
+    fn clip_corner_radius(self, radius: u16) -> ClipShape<Self, RoundedRectangle> {
+        ClipShape::new(self, RoundedRectangle::new(radius))
+    }
+
+    /// Modifies the built-in environment values seen by this view and its children
+    fn environment_override<F>(self, modify: F) -> EnvironmentOverride<Self, F>
+    where
+        F: Fn(&mut crate::environment::LayoutValues),
+    {
+        EnvironmentOverride::new(self, modify)
+    }
+
+    /// Makes a value available to this view and its children, keyed by the type of the value.
+    ///
+    /// Views read it with [`LayoutEnvironment::value`](crate::environment::LayoutEnvironment::value).
+    fn environment<T: 'static>(self, value: T) -> EnvironmentValue<Self, T> {
+        EnvironmentValue::new(self, value)
+    }
+
+    /// Applies modifiers only when the condition is true, keeping a single view type for both
+    /// outcomes.
+    ///
+    /// ```
+    /// use buoyant::environment::DefaultEnvironment;
+    /// use buoyant::font::BufferCharacterFont;
+    /// use buoyant::layout::Layout as _;
+    /// use buoyant::primitives::Point;
+    /// use buoyant::render::CharacterRender as _;
+    /// use buoyant::render_target::{CharacterRenderTarget as _, FixedTextBuffer};
+    /// use buoyant::view::{LayoutExtensions as _, Text};
+    ///
+    /// let env = DefaultEnvironment::new(());
+    /// let rows = |inset: bool| {
+    ///     let label = Text::str("hi", &BufferCharacterFont).when(inset, |v| v.padding(1));
+    ///     let mut buffer = FixedTextBuffer::<4, 3>::default();
+    ///     let layout = label.layout(buffer.size(), &env);
+    ///     label.render(&mut buffer, &layout, Point::zero(), &env);
+    ///     buffer.text
+    /// };
+    ///
+    /// assert_eq!(rows(true)[1], [' ', 'h', 'i', ' ']);
+    /// assert_eq!(rows(false)[0], ['h', 'i', ' ', ' ']);
+    /// ```
+    fn when<M>(self, condition: bool, modify: impl FnOnce(Self) -> M) -> When<M, Self> {
+        if condition {
+            When::Modified(modify(self))
+        } else {
+            When::Unmodified(self)
+        }
+    }
+
+    /// Reuses the previous layout of this view when it is offered the same size again
+    fn layout_cache(self) -> LayoutCache<Self>
+    where
+        Self: crate::layout::Layout,
+    {
+        LayoutCache::new(self)
+    }
 }
 
 impl<T: crate::layout::Layout> LayoutExtensions for T {}
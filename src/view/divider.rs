@@ -4,15 +4,51 @@ use crate::{
     primitives::{Point, Size},
     render::CharacterRender,
     render_target::CharacterRenderTarget,
+    view::modifier::ForegroundStyle,
 };
 
 pub struct Divider {
     pub weight: u16,
+    leading_inset: u16,
+    trailing_inset: u16,
 }
 
 impl Divider {
     pub fn new(weight: u16) -> Self {
-        Self { weight }
+        Self {
+            weight,
+            leading_inset: 0,
+            trailing_inset: 0,
+        }
+    }
+
+    /// Leaves space undrawn at the start and end of the line, without changing its size
+    pub fn insets(self, leading: u16, trailing: u16) -> Self {
+        Self {
+            leading_inset: leading,
+            trailing_inset: trailing,
+            ..self
+        }
+    }
+
+    /// Draws the line in a color other than the foreground color
+    pub fn color<C: Copy>(self, color: C) -> ForegroundStyle<Self, C> {
+        ForegroundStyle::new(color, self)
+    }
+
+    /// The origin and size of the drawn line within the divider's frame
+    fn line(&self, size: Size, direction: LayoutDirection) -> (Point, Size) {
+        let insets = self.leading_inset.saturating_add(self.trailing_inset);
+        match direction {
+            LayoutDirection::Horizontal => (
+                Point::new(0, self.leading_inset as i16),
+                Size::new(size.width, size.height.saturating_sub(insets)),
+            ),
+            LayoutDirection::Vertical => (
+                Point::new(self.leading_inset as i16, 0),
+                Size::new(size.width.saturating_sub(insets), size.height),
+            ),
+        }
     }
 }
 
@@ -25,6 +61,8 @@ impl Default for Divider {
 impl PartialEq for Divider {
     fn eq(&self, other: &Self) -> bool {
         self.weight == other.weight
+            && self.leading_inset == other.leading_inset
+            && self.trailing_inset == other.trailing_inset
     }
 }
 
@@ -59,10 +97,11 @@ impl<C: embedded_graphics_core::pixelcolor::PixelColor> crate::render::PixelRend
         env: &impl RenderEnvironment<Color = C>,
     ) {
         let color = env.foreground_color();
+        let (offset, size) = self.line(layout.resolved_size, env.layout_direction());
         _ = target.fill_solid(
             &Rectangle {
-                top_left: origin.into(),
-                size: layout.resolved_size.into(),
+                top_left: (origin + offset).into(),
+                size: size.into(),
             },
             color,
         );
@@ -78,14 +117,16 @@ impl<C: Copy> CharacterRender<C> for Divider {
         env: &impl RenderEnvironment<Color = C>,
     ) {
         let color = env.foreground_color();
+        let (offset, size) = self.line(layout.resolved_size, env.layout_direction());
+        let origin = origin + offset;
         match env.layout_direction() {
             LayoutDirection::Horizontal => {
-                for y in origin.y..origin.y + layout.resolved_size.height as i16 {
+                for y in origin.y..origin.y + size.height as i16 {
                     target.draw(Point::new(origin.x, y), '|', color);
                 }
             }
             LayoutDirection::Vertical => {
-                for x in origin.x..origin.x + layout.resolved_size.width as i16 {
+                for x in origin.x..origin.x + size.width as i16 {
                     target.draw(Point::new(x, origin.y), '-', color);
                 }
             }
@@ -143,4 +184,26 @@ mod tests {
         assert_eq!(buffer.text[0][4], '-');
         assert_eq!(buffer.text[1][0], ' ');
     }
+
+    #[test]
+    fn test_vertical_render_with_insets() {
+        let divider = Divider::new(1).insets(1, 1);
+        let mut buffer = FixedTextBuffer::<5, 1>::default();
+        let env = TestEnv::default().with_direction(LayoutDirection::Vertical);
+        let layout = divider.layout(buffer.size(), &env);
+        assert_eq!(layout.resolved_size, Size::new(5, 1));
+        divider.render(&mut buffer, &layout, Point::zero(), &env);
+        assert_eq!(buffer.text[0].iter().collect::<String>(), " --- ");
+    }
+
+    #[test]
+    fn test_horizontal_render_with_insets() {
+        let divider = Divider::new(1).insets(2, 1);
+        let mut buffer = FixedTextBuffer::<1, 5>::default();
+        let env = TestEnv::default().with_direction(LayoutDirection::Horizontal);
+        let layout = divider.layout(buffer.size(), &env);
+        divider.render(&mut buffer, &layout, Point::zero(), &env);
+        let column: String = buffer.text.iter().map(|row| row[0]).collect();
+        assert_eq!(column, "  || ");
+    }
 }
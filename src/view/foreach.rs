@@ -2,13 +2,15 @@ use core::cmp::{max, min};
 
 use crate::{
     environment::{LayoutEnvironment, RenderEnvironment},
-    layout::{HorizontalAlignment, Layout, LayoutDirection, ResolvedLayout},
+    layout::{HorizontalAlignment, Layout, LayoutDirection, ResolvedLayout, VerticalAlignment},
     primitives::{Point, Size},
     render::CharacterRender,
+    view::{ConditionalView, EmptyView},
 };
 
 struct ForEachEnvironment<'a, T> {
     inner_environment: &'a T,
+    direction: LayoutDirection,
 }
 
 impl<T: LayoutEnvironment> LayoutEnvironment for ForEachEnvironment<'_, T> {
@@ -17,7 +19,11 @@ impl<T: LayoutEnvironment> LayoutEnvironment for ForEachEnvironment<'_, T> {
     }
 
     fn layout_direction(&self) -> LayoutDirection {
-        LayoutDirection::Vertical
+        self.direction
+    }
+
+    fn value<V: 'static>(&self) -> Option<&V> {
+        self.inner_environment.value()
     }
 }
 
@@ -30,21 +36,32 @@ impl<Color: Copy, T: RenderEnvironment<Color = Color>> RenderEnvironment
     }
 }
 
-impl<'a, T: LayoutEnvironment> From<&'a T> for ForEachEnvironment<'a, T> {
-    fn from(environment: &'a T) -> Self {
+impl<'a, T> ForEachEnvironment<'a, T> {
+    fn new(environment: &'a T, direction: LayoutDirection) -> Self {
         Self {
             inner_environment: environment,
+            direction,
         }
     }
 }
 
-pub struct ForEach<const N: usize, I: IntoIterator, V, F>
+pub struct ForEach<const N: usize, I: IntoIterator, V, F, S = EmptyView>
 where
     F: Fn(&I::Item) -> V,
 {
     iter: I,
     build_view: F,
+    direction: LayoutDirection,
     alignment: HorizontalAlignment,
+    vertical_alignment: VerticalAlignment,
+    spacing: u16,
+    separator: S,
+}
+
+/// A view placed between consecutive items of a [`ForEach`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Separator<S> {
+    view: S,
 }
 
 impl<const N: usize, I: IntoIterator + Copy, V, F> ForEach<N, I, V, F>
@@ -56,28 +73,148 @@ where
         Self {
             iter,
             build_view,
+            direction: LayoutDirection::Vertical,
             alignment: HorizontalAlignment::default(),
+            vertical_alignment: VerticalAlignment::default(),
+            spacing: 0,
+            separator: EmptyView,
+        }
+    }
+
+    /// Arranges the items in a row instead of a column
+    pub fn horizontal(iter: I, build_view: F) -> Self {
+        Self {
+            direction: LayoutDirection::Horizontal,
+            ..Self::new(iter, build_view)
         }
     }
 
+    /// Places a separator view between consecutive items, but not before the first or after
+    /// the last.
+    ///
+    /// The separator takes up space like an item, with the spacing applied on both sides of it.
+    pub fn separated<S: Layout>(self, separator: S) -> ForEach<N, I, V, F, Separator<S>> {
+        ForEach {
+            iter: self.iter,
+            build_view: self.build_view,
+            direction: self.direction,
+            alignment: self.alignment,
+            vertical_alignment: self.vertical_alignment,
+            spacing: self.spacing,
+            separator: Separator { view: separator },
+        }
+    }
+}
+
+impl<const N: usize, I: IntoIterator + Copy, V, F, S> ForEach<N, I, V, F, S>
+where
+    V: Layout,
+    F: Fn(&I::Item) -> V,
+{
+    /// Aligns items within the width of a vertical `ForEach`
     pub fn with_alignment(mut self, alignment: HorizontalAlignment) -> Self {
         self.alignment = alignment;
         self
     }
+
+    /// Aligns items within the height of a horizontal `ForEach`
+    pub fn with_vertical_alignment(self, vertical_alignment: VerticalAlignment) -> Self {
+        Self {
+            vertical_alignment,
+            ..self
+        }
+    }
+
+    /// Sets the spacing between items, along the direction they are arranged in
+    pub fn with_spacing(self, spacing: u16) -> Self {
+        Self { spacing, ..self }
+    }
+
+    /// Shows the placeholder view in place of the collection when there are no items
+    pub fn empty<E: Layout>(self, placeholder: E) -> ConditionalView<E, Self> {
+        let is_empty = self.iter.into_iter().next().is_none();
+        ConditionalView::new(is_empty, placeholder, self)
+    }
 }
 
-impl<const N: usize, I: IntoIterator + Copy, V, F> Layout for ForEach<N, I, V, F>
+impl<const N: usize, I: IntoIterator, V: Layout, F, S> ForEach<N, I, V, F, S>
 where
-    V: Layout,
     F: Fn(&I::Item) -> V,
 {
-    type Sublayout = heapless::Vec<ResolvedLayout<V::Sublayout>, N>;
+    /// Horizontal layout is vertical layout with the axes swapped
+    fn orient(&self, size: Size) -> Size {
+        match self.direction {
+            LayoutDirection::Vertical => size,
+            LayoutDirection::Horizontal => Size::new(size.height, size.width),
+        }
+    }
+
+    /// The length of a size along the direction items are arranged in
+    fn main_axis(&self, size: Size) -> u16 {
+        self.orient(size).height
+    }
+
+    /// The distance between consecutive items, including the length of any separator
+    fn gap(&self, separator_length: Option<u16>) -> u16 {
+        match separator_length {
+            Some(length) => self.spacing.saturating_mul(2).saturating_add(length),
+            None => self.spacing,
+        }
+    }
+
+    /// The offset of a view from the origin of the `ForEach`, for a view at `offset` along the
+    /// main axis
+    fn aligned_origin(&self, resolved_size: Size, size: Size, offset: i16) -> Point {
+        match self.direction {
+            LayoutDirection::Vertical => Point::new(
+                self.alignment
+                    .align(resolved_size.width as i16, size.width as i16),
+                offset,
+            ),
+            LayoutDirection::Horizontal => Point::new(
+                offset,
+                self.vertical_alignment
+                    .align(resolved_size.height as i16, size.height as i16),
+            ),
+        }
+    }
+
+    /// The origin of each item, along with the origin of the separator that follows it, if any
+    fn item_origins<'a>(
+        &'a self,
+        items: &'a [ResolvedLayout<V::Sublayout>],
+        resolved_size: Size,
+        separator_size: Option<Size>,
+    ) -> impl Iterator<Item = (Point, Option<Point>)> + 'a {
+        let gap = self.gap(separator_size.map(|size| self.main_axis(size))) as i16;
+        let last = items.len().saturating_sub(1);
+        let mut offset: i16 = 0;
+        items.iter().enumerate().map(move |(i, item_layout)| {
+            let size = item_layout.resolved_size;
+            let item_origin = self.aligned_origin(resolved_size, size, offset);
+            offset += self.main_axis(size) as i16;
+            let separator_origin = separator_size.filter(|_| i < last).map(|separator_size| {
+                self.aligned_origin(resolved_size, separator_size, offset + self.spacing as i16)
+            });
+            offset += gap;
+            (item_origin, separator_origin)
+        })
+    }
 
+    /// Lays out the items with `gap` between each, returning their layouts and the total size
+    //
     // This layout implementation trades extra work for lower memory usage as embedded is the
     // primary target environment. Views are repeatedly created for every layout call, but it
     // should be assumed that this is cheap
-    fn layout(&self, offer: Size, env: &impl LayoutEnvironment) -> ResolvedLayout<Self::Sublayout> {
-        let env = &ForEachEnvironment::from(env);
+    fn layout_items(
+        &self,
+        offer: Size,
+        env: &impl LayoutEnvironment,
+        gap: u16,
+    ) -> (heapless::Vec<ResolvedLayout<V::Sublayout>, N>, Size)
+    where
+        I: Copy,
+    {
         let mut sublayouts: heapless::Vec<ResolvedLayout<V::Sublayout>, N> = heapless::Vec::new();
 
         // TODO: consolidate array init to avoid accidentally allowing them to become different
@@ -97,16 +234,58 @@ where
         }
 
         let layout_fn = |index: usize, offer: Size| {
-            let layout = (self.build_view)(&items[index]).layout(offer, env);
+            let layout = (self.build_view)(&items[index]).layout(self.orient(offer), env);
             let size = layout.resolved_size;
             sublayouts[index] = layout;
-            size
+            self.orient(size)
         };
 
-        let size = layout_n(&mut subview_stages, offer, 0, layout_fn);
+        let size = layout_n(&mut subview_stages, self.orient(offer), gap, layout_fn);
+        (sublayouts, self.orient(size))
+    }
+}
+
+impl<const N: usize, I: IntoIterator + Copy, V, F> Layout for ForEach<N, I, V, F>
+where
+    V: Layout,
+    F: Fn(&I::Item) -> V,
+{
+    type Sublayout = heapless::Vec<ResolvedLayout<V::Sublayout>, N>;
+
+    fn layout(&self, offer: Size, env: &impl LayoutEnvironment) -> ResolvedLayout<Self::Sublayout> {
+        let env = &ForEachEnvironment::new(env, self.direction);
+        let (sublayouts, resolved_size) = self.layout_items(offer, env, self.gap(None));
         ResolvedLayout {
             sublayouts,
-            resolved_size: size,
+            resolved_size,
+        }
+    }
+}
+
+impl<const N: usize, I: IntoIterator + Copy, V, F, S> Layout for ForEach<N, I, V, F, Separator<S>>
+where
+    V: Layout,
+    F: Fn(&I::Item) -> V,
+    S: Layout,
+{
+    type Sublayout = (
+        heapless::Vec<ResolvedLayout<V::Sublayout>, N>,
+        ResolvedLayout<S::Sublayout>,
+    );
+
+    fn layout(&self, offer: Size, env: &impl LayoutEnvironment) -> ResolvedLayout<Self::Sublayout> {
+        let env = &ForEachEnvironment::new(env, self.direction);
+        // Separators are laid out once to find their length, then again to fit the items
+        let separator = &self.separator.view;
+        let separator_length = self.main_axis(separator.layout(offer, env).resolved_size);
+        let (sublayouts, resolved_size) =
+            self.layout_items(offer, env, self.gap(Some(separator_length)));
+        let cross_axis = self.orient(resolved_size).width;
+        let separator_layout =
+            separator.layout(self.orient(Size::new(cross_axis, separator_length)), env);
+        ResolvedLayout {
+            sublayouts: (sublayouts, separator_layout),
+            resolved_size,
         }
     }
 }
@@ -126,7 +305,7 @@ fn layout_n<const N: usize>(
 ) -> Size {
     let mut remaining_height = offer
         .height
-        .saturating_sub(spacing * (subviews.len() - 1) as u16);
+        .saturating_sub(spacing.saturating_mul(subviews.len().saturating_sub(1) as u16));
 
     loop {
         // collect the unsized subviews with the max layout priority into a group
@@ -265,24 +444,53 @@ where
         origin: crate::primitives::Point,
         env: &impl RenderEnvironment<Color = Pixel>,
     ) {
-        let env = &ForEachEnvironment::from(env);
-
-        let mut height = 0;
-
-        for (item_layout, item) in layout.sublayouts.iter().zip(self.iter.into_iter()) {
-            // TODO: defaulting to center alignment
-            let aligned_origin = origin
-                + Point::new(
-                    self.alignment.align(
-                        layout.resolved_size.width as i16,
-                        item_layout.resolved_size.width as i16,
-                    ),
-                    height,
-                );
+        let env = &ForEachEnvironment::new(env, self.direction);
+
+        let origins = self.item_origins(&layout.sublayouts, layout.resolved_size, None);
+        for ((item_layout, item), (item_origin, _)) in
+            layout.sublayouts.iter().zip(self.iter).zip(origins)
+        {
             let view = (self.build_view)(&item);
-            view.render(target, item_layout, aligned_origin, env);
+            view.render(target, item_layout, origin + item_origin, env);
+        }
+    }
+}
 
-            height += item_layout.resolved_size.height as i16;
+impl<const N: usize, Pixel: Copy, I: IntoIterator + Copy, V, F, S> CharacterRender<Pixel>
+    for ForEach<N, I, V, F, Separator<S>>
+where
+    V: CharacterRender<Pixel>,
+    F: Fn(&I::Item) -> V,
+    S: CharacterRender<Pixel>,
+{
+    fn render(
+        &self,
+        target: &mut impl crate::render_target::CharacterRenderTarget<Color = Pixel>,
+        layout: &ResolvedLayout<Self::Sublayout>,
+        origin: crate::primitives::Point,
+        env: &impl RenderEnvironment<Color = Pixel>,
+    ) {
+        let env = &ForEachEnvironment::new(env, self.direction);
+
+        let (items, separator_layout) = &layout.sublayouts;
+        let origins = self.item_origins(
+            items,
+            layout.resolved_size,
+            Some(separator_layout.resolved_size),
+        );
+        for ((item_layout, item), (item_origin, separator_origin)) in
+            items.iter().zip(self.iter).zip(origins)
+        {
+            let view = (self.build_view)(&item);
+            view.render(target, item_layout, origin + item_origin, env);
+            if let Some(separator_origin) = separator_origin {
+                self.separator.view.render(
+                    target,
+                    separator_layout,
+                    origin + separator_origin,
+                    env,
+                );
+            }
         }
     }
 }
@@ -307,24 +515,55 @@ where
         origin: Point,
         env: &impl RenderEnvironment<Color = Pixel>,
     ) {
-        let env = &ForEachEnvironment::from(env);
-
-        let mut height = 0;
-
-        for (item_layout, item) in layout.sublayouts.iter().zip(self.iter.into_iter()) {
-            // TODO: defaulting to center alignment
-            let aligned_origin = origin
-                + Point::new(
-                    self.alignment.align(
-                        layout.resolved_size.width as i16,
-                        item_layout.resolved_size.width as i16,
-                    ),
-                    height,
-                );
+        let env = &ForEachEnvironment::new(env, self.direction);
+
+        let origins = self.item_origins(&layout.sublayouts, layout.resolved_size, None);
+        for ((item_layout, item), (item_origin, _)) in
+            layout.sublayouts.iter().zip(self.iter).zip(origins)
+        {
             let view = (self.build_view)(&item);
-            view.render(target, item_layout, aligned_origin, env);
+            view.render(target, item_layout, origin + item_origin, env);
+        }
+    }
+}
 
-            height += item_layout.resolved_size.height as i16;
+#[cfg(feature = "embedded-graphics")]
+impl<const N: usize, Pixel: Copy, I: IntoIterator + Copy, V, F, S> crate::render::PixelRender<Pixel>
+    for ForEach<N, I, V, F, Separator<S>>
+where
+    V: crate::render::PixelRender<Pixel>,
+    F: Fn(&I::Item) -> V,
+    S: crate::render::PixelRender<Pixel>,
+    Pixel: embedded_graphics_core::pixelcolor::PixelColor,
+{
+    fn render(
+        &self,
+        target: &mut impl DrawTarget<Color = Pixel>,
+        layout: &ResolvedLayout<Self::Sublayout>,
+        origin: Point,
+        env: &impl RenderEnvironment<Color = Pixel>,
+    ) {
+        let env = &ForEachEnvironment::new(env, self.direction);
+
+        let (items, separator_layout) = &layout.sublayouts;
+        let origins = self.item_origins(
+            items,
+            layout.resolved_size,
+            Some(separator_layout.resolved_size),
+        );
+        for ((item_layout, item), (item_origin, separator_origin)) in
+            items.iter().zip(self.iter).zip(origins)
+        {
+            let view = (self.build_view)(&item);
+            view.render(target, item_layout, origin + item_origin, env);
+            if let Some(separator_origin) = separator_origin {
+                self.separator.view.render(
+                    target,
+                    separator_layout,
+                    origin + separator_origin,
+                    env,
+                );
+            }
         }
     }
 }
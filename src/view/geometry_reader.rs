@@ -0,0 +1,75 @@
+use crate::{
+    environment::{LayoutEnvironment, RenderEnvironment},
+    layout::{Layout, ResolvedLayout},
+    primitives::{Point, Size},
+    render::CharacterRender,
+    render_target::CharacterRenderTarget,
+};
+
+/// Builds its content from the size it is offered, so the content can adapt to the space
+/// available.
+///
+/// The reader always takes the full offer, and its content is placed at the top leading corner.
+pub struct GeometryReader<F> {
+    build_view: F,
+}
+
+impl<V, F: Fn(Size) -> V> GeometryReader<F> {
+    pub fn new(build_view: F) -> Self {
+        Self { build_view }
+    }
+}
+
+impl<F> PartialEq for GeometryReader<F> {
+    fn eq(&self, _: &Self) -> bool {
+        true
+    }
+}
+
+impl<V: Layout, F: Fn(Size) -> V> Layout for GeometryReader<F> {
+    type Sublayout = ResolvedLayout<V::Sublayout>;
+
+    fn layout(&self, offer: Size, env: &impl LayoutEnvironment) -> ResolvedLayout<Self::Sublayout> {
+        ResolvedLayout {
+            sublayouts: (self.build_view)(offer).layout(offer, env),
+            resolved_size: offer,
+        }
+    }
+}
+
+impl<Pixel: Copy, V, F> CharacterRender<Pixel> for GeometryReader<F>
+where
+    V: CharacterRender<Pixel>,
+    F: Fn(Size) -> V,
+{
+    fn render(
+        &self,
+        target: &mut impl CharacterRenderTarget<Color = Pixel>,
+        layout: &ResolvedLayout<Self::Sublayout>,
+        origin: Point,
+        env: &impl RenderEnvironment<Color = Pixel>,
+    ) {
+        (self.build_view)(layout.resolved_size).render(target, &layout.sublayouts, origin, env);
+    }
+}
+
+#[cfg(feature = "embedded-graphics")]
+use embedded_graphics::draw_target::DrawTarget;
+
+#[cfg(feature = "embedded-graphics")]
+impl<Pixel, V, F> crate::render::PixelRender<Pixel> for GeometryReader<F>
+where
+    V: crate::render::PixelRender<Pixel>,
+    F: Fn(Size) -> V,
+    Pixel: embedded_graphics_core::pixelcolor::PixelColor,
+{
+    fn render(
+        &self,
+        target: &mut impl DrawTarget<Color = Pixel>,
+        layout: &ResolvedLayout<Self::Sublayout>,
+        origin: Point,
+        env: &impl RenderEnvironment<Color = Pixel>,
+    ) {
+        (self.build_view)(layout.resolved_size).render(target, &layout.sublayouts, origin, env);
+    }
+}
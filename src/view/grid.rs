@@ -0,0 +1,256 @@
+use core::cmp::{max, min};
+
+use crate::{
+    environment::{LayoutEnvironment, RenderEnvironment},
+    layout::{Layout, ResolvedLayout},
+    primitives::{Point, Size},
+    render::CharacterRender,
+    render_target::CharacterRenderTarget,
+};
+
+/// The width of a grid column
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TrackSize {
+    /// A column of exactly this width
+    Fixed(u16),
+    /// A share of the width left over after fixed and auto columns, relative to the weights of
+    /// the other flexible columns
+    Flexible(u16),
+    /// A column as wide as its widest cell
+    Auto,
+}
+
+/// Flows views into rows of `COLS` columns, starting a new row when each row is full.
+///
+/// The height of each row is the height of its tallest cell. Cells are placed at the top
+/// leading corner of their column.
+pub struct Grid<const N: usize, const COLS: usize, I: IntoIterator, V, F>
+where
+    F: Fn(&I::Item) -> V,
+{
+    columns: [TrackSize; COLS],
+    iter: I,
+    build_view: F,
+    spacing: u16,
+}
+
+impl<const N: usize, const COLS: usize, I: IntoIterator + Copy, V, F> Grid<N, COLS, I, V, F>
+where
+    V: Layout,
+    F: Fn(&I::Item) -> V,
+{
+    pub fn new(columns: [TrackSize; COLS], iter: I, build_view: F) -> Self {
+        const { assert!(COLS > 0, "a grid needs at least one column") };
+        Self {
+            columns,
+            iter,
+            build_view,
+            spacing: 0,
+        }
+    }
+
+    /// Sets the spacing between both rows and columns
+    pub fn with_spacing(self, spacing: u16) -> Self {
+        Self { spacing, ..self }
+    }
+}
+
+impl<const N: usize, const COLS: usize, I: IntoIterator, V, F> PartialEq for Grid<N, COLS, I, V, F>
+where
+    F: Fn(&I::Item) -> V,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.columns == other.columns && self.spacing == other.spacing
+    }
+}
+
+/// The resolved column widths and cell layouts of a [`Grid`]
+#[derive(Clone, PartialEq)]
+pub struct GridLayout<S: Clone + PartialEq, const N: usize, const COLS: usize> {
+    column_widths: [u16; COLS],
+    cells: heapless::Vec<ResolvedLayout<S>, N>,
+}
+
+impl<S: Clone + PartialEq, const N: usize, const COLS: usize> GridLayout<S, N, COLS> {
+    /// The width of each column
+    pub fn column_widths(&self) -> &[u16; COLS] {
+        &self.column_widths
+    }
+
+    /// The layout of each cell, in the order the cells were produced
+    pub fn cells(&self) -> &[ResolvedLayout<S>] {
+        &self.cells
+    }
+
+    /// The origin of each cell, relative to the origin of the grid
+    fn cell_origins(&self, spacing: u16) -> impl Iterator<Item = Point> + '_ {
+        let mut y: u16 = 0;
+        self.cells.chunks(COLS).flat_map(move |row| {
+            let row_y = y;
+            let row_height = row.iter().map(|c| c.resolved_size.height).max();
+            y = y
+                .saturating_add(row_height.unwrap_or_default())
+                .saturating_add(spacing);
+            let mut x: u16 = 0;
+            self.column_widths.iter().take(row.len()).map(move |width| {
+                let origin = Point::new(x as i16, row_y as i16);
+                x = x.saturating_add(*width).saturating_add(spacing);
+                origin
+            })
+        })
+    }
+}
+
+impl<const N: usize, const COLS: usize, I: IntoIterator + Copy, V, F> Layout
+    for Grid<N, COLS, I, V, F>
+where
+    V: Layout,
+    F: Fn(&I::Item) -> V,
+{
+    type Sublayout = GridLayout<V::Sublayout, N, COLS>;
+
+    fn layout(&self, offer: Size, env: &impl LayoutEnvironment) -> ResolvedLayout<Self::Sublayout> {
+        let mut items: heapless::Vec<I::Item, N> = heapless::Vec::new();
+        _ = self.iter.into_iter().try_for_each(|item| items.push(item));
+
+        let spacing = self.spacing.saturating_mul(COLS.saturating_sub(1) as u16);
+        let fixed_width = self
+            .columns
+            .iter()
+            .map(|track| match track {
+                TrackSize::Fixed(width) => *width,
+                _ => 0,
+            })
+            .fold(spacing, u16::saturating_add);
+
+        let mut column_widths = [0; COLS];
+        let mut remaining_width = offer.width.saturating_sub(fixed_width);
+        for (column, track) in self.columns.iter().enumerate() {
+            match track {
+                TrackSize::Fixed(width) => column_widths[column] = *width,
+                TrackSize::Auto => {
+                    let width = items
+                        .iter()
+                        .skip(column)
+                        .step_by(COLS)
+                        .map(|item| {
+                            (self.build_view)(item)
+                                .layout(Size::new(remaining_width, offer.height), env)
+                                .resolved_size
+                                .width
+                        })
+                        .max()
+                        .unwrap_or_default();
+                    column_widths[column] = min(width, remaining_width);
+                    remaining_width -= column_widths[column];
+                }
+                TrackSize::Flexible(_) => {}
+            }
+        }
+
+        // Split the leftover width between flexible columns by weight, with any remainder
+        // going to the leading columns
+        let total_weight: u32 = self
+            .columns
+            .iter()
+            .map(|track| match track {
+                TrackSize::Flexible(weight) => *weight as u32,
+                _ => 0,
+            })
+            .sum();
+        let mut leftover = remaining_width;
+        for (column, track) in self.columns.iter().enumerate() {
+            if let TrackSize::Flexible(weight) = track {
+                let width = (remaining_width as u32 * *weight as u32)
+                    .checked_div(total_weight)
+                    .unwrap_or_default() as u16;
+                column_widths[column] = width;
+                leftover -= width;
+            }
+        }
+        for (column, track) in self.columns.iter().enumerate() {
+            if leftover == 0 {
+                break;
+            }
+            if let TrackSize::Flexible(weight) = track {
+                if *weight > 0 {
+                    column_widths[column] += 1;
+                    leftover -= 1;
+                }
+            }
+        }
+
+        let mut cells: heapless::Vec<ResolvedLayout<V::Sublayout>, N> = heapless::Vec::new();
+        let mut height: u16 = 0;
+        for (row, row_items) in items.chunks(COLS).enumerate() {
+            let mut row_height = 0;
+            for (item, width) in row_items.iter().zip(column_widths) {
+                let layout = (self.build_view)(item).layout(Size::new(width, offer.height), env);
+                row_height = max(row_height, layout.resolved_size.height);
+                _ = cells.push(layout);
+            }
+            if row > 0 {
+                height = height.saturating_add(self.spacing);
+            }
+            height = height.saturating_add(row_height);
+        }
+
+        let width = column_widths
+            .iter()
+            .fold(spacing, |total, width| total.saturating_add(*width));
+        ResolvedLayout {
+            sublayouts: GridLayout {
+                column_widths,
+                cells,
+            },
+            resolved_size: Size::new(min(width, offer.width), min(height, offer.height)),
+        }
+    }
+}
+
+impl<const N: usize, const COLS: usize, Pixel: Copy, I: IntoIterator + Copy, V, F>
+    CharacterRender<Pixel> for Grid<N, COLS, I, V, F>
+where
+    V: CharacterRender<Pixel>,
+    F: Fn(&I::Item) -> V,
+{
+    fn render(
+        &self,
+        target: &mut impl CharacterRenderTarget<Color = Pixel>,
+        layout: &ResolvedLayout<Self::Sublayout>,
+        origin: Point,
+        env: &impl RenderEnvironment<Color = Pixel>,
+    ) {
+        let cells = layout.sublayouts.cells.iter();
+        let origins = layout.sublayouts.cell_origins(self.spacing);
+        for ((cell_layout, cell_origin), item) in cells.zip(origins).zip(self.iter) {
+            (self.build_view)(&item).render(target, cell_layout, origin + cell_origin, env);
+        }
+    }
+}
+
+#[cfg(feature = "embedded-graphics")]
+use embedded_graphics::draw_target::DrawTarget;
+
+#[cfg(feature = "embedded-graphics")]
+impl<const N: usize, const COLS: usize, Pixel, I: IntoIterator + Copy, V, F>
+    crate::render::PixelRender<Pixel> for Grid<N, COLS, I, V, F>
+where
+    V: crate::render::PixelRender<Pixel>,
+    F: Fn(&I::Item) -> V,
+    Pixel: embedded_graphics_core::pixelcolor::PixelColor,
+{
+    fn render(
+        &self,
+        target: &mut impl DrawTarget<Color = Pixel>,
+        layout: &ResolvedLayout<Self::Sublayout>,
+        origin: Point,
+        env: &impl RenderEnvironment<Color = Pixel>,
+    ) {
+        let cells = layout.sublayouts.cells.iter();
+        let origins = layout.sublayouts.cell_origins(self.spacing);
+        for ((cell_layout, cell_origin), item) in cells.zip(origins).zip(self.iter) {
+            (self.build_view)(&item).render(target, cell_layout, origin + cell_origin, env);
+        }
+    }
+}
@@ -2,16 +2,25 @@ use core::cmp::{max, min};
 
 use crate::{
     environment::{LayoutEnvironment, RenderEnvironment},
-    layout::{Layout, LayoutDirection, ResolvedLayout, VerticalAlignment},
+    layout::{Layout, LayoutDirection, OverflowBehavior, ResolvedLayout, VerticalAlignment},
     primitives::{Point, Size},
     render::CharacterRender,
     render_target::CharacterRenderTarget,
 };
 
+use super::modifier::ClippedTarget;
+
+/// Lays out views side by side, offering space to higher priority views first.
+///
+/// Before sizing each priority group, the stack holds back the minimum width of every lower
+/// priority view, measured by offering it zero width. Higher priority views give way to lower
+/// priority views which refuse to shrink, such as a spacer with a minimum length, while lower
+/// priority views that can shrink to nothing reserve no space.
 pub struct HStack<T> {
     items: T,
     alignment: VerticalAlignment,
     spacing: u16,
+    overflow: OverflowBehavior,
 }
 
 struct HorizontalEnvironment<'a, T> {
@@ -25,6 +34,10 @@ impl<T: LayoutEnvironment> LayoutEnvironment for HorizontalEnvironment<'_, T> {
     fn layout_direction(&self) -> LayoutDirection {
         LayoutDirection::Horizontal
     }
+
+    fn value<V: 'static>(&self) -> Option<&V> {
+        self.inner_environment.value()
+    }
 }
 
 impl<Color: Copy, T: RenderEnvironment<Color = Color>> RenderEnvironment
@@ -52,11 +65,74 @@ impl<T> HStack<T> {
     pub fn with_alignment(self, alignment: VerticalAlignment) -> Self {
         Self { alignment, ..self }
     }
+
+    /// Sets what happens to children that do not fit in the stack
+    pub fn with_overflow_behavior(self, overflow: OverflowBehavior) -> Self {
+        Self { overflow, ..self }
+    }
+
+    /// Whether children are clipped to the bounds of the stack along its axis
+    fn clips(&self) -> bool {
+        self.overflow == OverflowBehavior::ClipLast
+    }
+}
+
+impl<T> HStack<T> {
+    /// The baseline and height of a child, using its bottom edge when it has no baseline
+    fn child_metrics<V: Layout>(
+        &self,
+        child: &V,
+        layout: &ResolvedLayout<V::Sublayout>,
+    ) -> (u16, u16) {
+        let height = layout.resolved_size.height;
+        let baseline = match self.alignment {
+            VerticalAlignment::FirstTextBaseline => child.first_baseline(layout),
+            VerticalAlignment::LastTextBaseline => child.last_baseline(layout),
+            _ => None,
+        };
+        (baseline.unwrap_or(height), height)
+    }
+
+    /// The vertical offset of each child within a stack of the given height
+    fn vertical_offsets<const N: usize>(&self, height: u16, children: [(u16, u16); N]) -> [i16; N] {
+        match self.alignment {
+            VerticalAlignment::FirstTextBaseline | VerticalAlignment::LastTextBaseline => {
+                let baseline = children.iter().map(|(b, _)| *b).max().unwrap_or_default();
+                children.map(|(b, _)| (baseline - b) as i16)
+            }
+            alignment => children.map(|(_, h)| alignment.align(height as i16, h as i16)),
+        }
+    }
+
+    /// Grows the stack to fit children that were shifted to line up their baselines
+    fn fit_baselines<const N: usize>(
+        &self,
+        size: Size,
+        offer: Size,
+        children: [(u16, u16); N],
+    ) -> Size {
+        if !matches!(
+            self.alignment,
+            VerticalAlignment::FirstTextBaseline | VerticalAlignment::LastTextBaseline
+        ) {
+            return size;
+        }
+        let offsets = self.vertical_offsets(size.height, children);
+        let height = offsets
+            .iter()
+            .zip(children)
+            .map(|(offset, (_, h))| (*offset as u16).saturating_add(h))
+            .max()
+            .unwrap_or_default();
+        Size::new(size.width, max(size.height, min(height, offer.height)))
+    }
 }
 
 impl<T> PartialEq for HStack<T> {
     fn eq(&self, other: &Self) -> bool {
-        self.spacing == other.spacing && self.alignment == other.alignment
+        self.spacing == other.spacing
+            && self.alignment == other.alignment
+            && self.overflow == other.overflow
     }
 }
 
@@ -66,6 +142,7 @@ impl<T> HStack<T> {
             items,
             alignment: VerticalAlignment::default(),
             spacing: 0,
+            overflow: OverflowBehavior::default(),
         }
     }
 }
@@ -99,9 +176,14 @@ impl<U: Layout, V: Layout> Layout for HStack<(U, V)> {
             (LayoutStage::Unsized, &mut f1, self.items.1.priority()),
         ];
         let total_size = layout_n(&mut subviews, offer, self.spacing);
+        let sublayouts = (c0.unwrap(), c1.unwrap());
+        let children = [
+            self.child_metrics(&self.items.0, &sublayouts.0),
+            self.child_metrics(&self.items.1, &sublayouts.1),
+        ];
         ResolvedLayout {
-            sublayouts: (c0.unwrap(), c1.unwrap()),
-            resolved_size: total_size,
+            resolved_size: self.fit_baselines(total_size, offer, children),
+            sublayouts,
         }
     }
 }
@@ -147,9 +229,15 @@ impl<U: Layout, V: Layout, W: Layout> Layout for HStack<(U, V, W)> {
             (LayoutStage::Unsized, &mut f2, self.items.2.priority()),
         ];
         let total_size = layout_n(&mut subviews, offer, self.spacing);
+        let sublayouts = (c0.unwrap(), c1.unwrap(), c2.unwrap());
+        let children = [
+            self.child_metrics(&self.items.0, &sublayouts.0),
+            self.child_metrics(&self.items.1, &sublayouts.1),
+            self.child_metrics(&self.items.2, &sublayouts.2),
+        ];
         ResolvedLayout {
-            sublayouts: (c0.unwrap(), c1.unwrap(), c2.unwrap()),
-            resolved_size: total_size,
+            resolved_size: self.fit_baselines(total_size, offer, children),
+            sublayouts,
         }
     }
 }
@@ -196,6 +284,15 @@ fn layout_n<const N: usize>(
             break;
         }
 
+        // Hold back the minimum width of lower priority views, so they are not squeezed out
+        let reserved = subviews
+            .iter_mut()
+            .filter(|(stage, _, priority)| *stage == LayoutStage::Unsized && *priority < max)
+            .map(|(_, layout, _)| layout(Size::new(0, offer.height)).width)
+            .fold(0, u16::saturating_add)
+            .min(remaining_width);
+        remaining_width -= reserved;
+
         // Size all the unsized views that are unwilling to shrink
         let mut group_offer = Size::new(remaining_width / slice_len as u16, offer.height);
         let mut remainder = remaining_width as usize % slice_len;
@@ -267,6 +364,7 @@ fn layout_n<const N: usize>(
                 }
             }
         }
+        remaining_width += reserved;
     }
 
     // At this point all the subviews should have either a final or a candidate size
@@ -308,29 +406,26 @@ where
         origin: Point,
         env: &impl RenderEnvironment<Color = Pixel>,
     ) {
+        let target =
+            &mut ClippedTarget::new(target, origin, layout.resolved_size, self.clips(), false);
         let env = HorizontalEnvironment::from(env);
         let mut width = 0;
-
-        let offset = Point::new(
-            width,
-            self.alignment.align(
-                layout.resolved_size.height as i16,
-                layout.sublayouts.0.resolved_size.height as i16,
-            ),
+        let offsets = self.vertical_offsets(
+            layout.resolved_size.height,
+            [
+                self.child_metrics(&self.items.0, &layout.sublayouts.0),
+                self.child_metrics(&self.items.1, &layout.sublayouts.1),
+            ],
         );
 
+        let offset = Point::new(width, offsets[0]);
+
         self.items
             .0
             .render(target, &layout.sublayouts.0, origin + offset, &env);
 
         width += (layout.sublayouts.0.resolved_size.width + self.spacing) as i16;
-        let offset = Point::new(
-            width,
-            self.alignment.align(
-                layout.resolved_size.height as i16,
-                layout.sublayouts.1.resolved_size.height as i16,
-            ),
-        );
+        let offset = Point::new(width, offsets[1]);
 
         self.items
             .1
@@ -351,42 +446,34 @@ where
         origin: Point,
         env: &impl RenderEnvironment<Color = Pixel>,
     ) {
+        let target =
+            &mut ClippedTarget::new(target, origin, layout.resolved_size, self.clips(), false);
         let env = HorizontalEnvironment::from(env);
         let mut width = 0;
-
-        let offset = Point::new(
-            width,
-            self.alignment.align(
-                layout.resolved_size.height as i16,
-                layout.sublayouts.0.resolved_size.height as i16,
-            ),
+        let offsets = self.vertical_offsets(
+            layout.resolved_size.height,
+            [
+                self.child_metrics(&self.items.0, &layout.sublayouts.0),
+                self.child_metrics(&self.items.1, &layout.sublayouts.1),
+                self.child_metrics(&self.items.2, &layout.sublayouts.2),
+            ],
         );
 
+        let offset = Point::new(width, offsets[0]);
+
         self.items
             .0
             .render(target, &layout.sublayouts.0, origin + offset, &env);
 
         width += (layout.sublayouts.0.resolved_size.width + self.spacing) as i16;
-        let offset = Point::new(
-            width,
-            self.alignment.align(
-                layout.resolved_size.height as i16,
-                layout.sublayouts.1.resolved_size.height as i16,
-            ),
-        );
+        let offset = Point::new(width, offsets[1]);
 
         self.items
             .1
             .render(target, &layout.sublayouts.1, origin + offset, &env);
 
         width += (layout.sublayouts.1.resolved_size.width + self.spacing) as i16;
-        let offset = Point::new(
-            width,
-            self.alignment.align(
-                layout.resolved_size.height as i16,
-                layout.sublayouts.2.resolved_size.height as i16,
-            ),
-        );
+        let offset = Point::new(width, offsets[2]);
 
         self.items
             .2
@@ -397,7 +484,10 @@ where
 // -- Embedded Render
 
 #[cfg(feature = "embedded-graphics")]
-use embedded_graphics::draw_target::DrawTarget;
+use embedded_graphics::draw_target::{DrawTarget, DrawTargetExt as _};
+
+#[cfg(feature = "embedded-graphics")]
+use super::modifier::clip_area;
 
 #[cfg(feature = "embedded-graphics")]
 impl<Pixel, U, V> crate::render::PixelRender<Pixel> for HStack<(U, V)>
@@ -413,29 +503,30 @@ where
         origin: Point,
         env: &impl RenderEnvironment<Color = Pixel>,
     ) {
+        let target = &mut target.clipped(&clip_area(
+            origin,
+            layout.resolved_size,
+            self.clips(),
+            false,
+        ));
         let env = HorizontalEnvironment::from(env);
         let mut width = 0;
-
-        let offset = Point::new(
-            width,
-            self.alignment.align(
-                layout.resolved_size.height as i16,
-                layout.sublayouts.0.resolved_size.height as i16,
-            ),
+        let offsets = self.vertical_offsets(
+            layout.resolved_size.height,
+            [
+                self.child_metrics(&self.items.0, &layout.sublayouts.0),
+                self.child_metrics(&self.items.1, &layout.sublayouts.1),
+            ],
         );
 
+        let offset = Point::new(width, offsets[0]);
+
         self.items
             .0
             .render(target, &layout.sublayouts.0, origin + offset, &env);
 
         width += (layout.sublayouts.0.resolved_size.width + self.spacing) as i16;
-        let offset = Point::new(
-            width,
-            self.alignment.align(
-                layout.resolved_size.height as i16,
-                layout.sublayouts.1.resolved_size.height as i16,
-            ),
-        );
+        let offset = Point::new(width, offsets[1]);
 
         self.items
             .1
@@ -458,42 +549,38 @@ where
         origin: Point,
         env: &impl RenderEnvironment<Color = Pixel>,
     ) {
+        let target = &mut target.clipped(&clip_area(
+            origin,
+            layout.resolved_size,
+            self.clips(),
+            false,
+        ));
         let env = HorizontalEnvironment::from(env);
         let mut width = 0;
-
-        let offset = Point::new(
-            width,
-            self.alignment.align(
-                layout.resolved_size.height as i16,
-                layout.sublayouts.0.resolved_size.height as i16,
-            ),
+        let offsets = self.vertical_offsets(
+            layout.resolved_size.height,
+            [
+                self.child_metrics(&self.items.0, &layout.sublayouts.0),
+                self.child_metrics(&self.items.1, &layout.sublayouts.1),
+                self.child_metrics(&self.items.2, &layout.sublayouts.2),
+            ],
         );
 
+        let offset = Point::new(width, offsets[0]);
+
         self.items
             .0
             .render(target, &layout.sublayouts.0, origin + offset, &env);
 
         width += (layout.sublayouts.0.resolved_size.width + self.spacing) as i16;
-        let offset = Point::new(
-            width,
-            self.alignment.align(
-                layout.resolved_size.height as i16,
-                layout.sublayouts.1.resolved_size.height as i16,
-            ),
-        );
+        let offset = Point::new(width, offsets[1]);
 
         self.items
             .1
             .render(target, &layout.sublayouts.1, origin + offset, &env);
 
         width += (layout.sublayouts.1.resolved_size.width + self.spacing) as i16;
-        let offset = Point::new(
-            width,
-            self.alignment.align(
-                layout.resolved_size.height as i16,
-                layout.sublayouts.2.resolved_size.height as i16,
-            ),
-        );
+        let offset = Point::new(width, offsets[2]);
 
         self.items
             .2
@@ -1,11 +1,26 @@
+mod clip_shape;
+mod clipped;
+mod environment_override;
+mod environment_value;
 mod fixed_frame;
 mod flex_frame;
 mod foreground_color;
+mod layout_cache;
 mod padding;
 mod priority;
+mod when;
 
+pub use clip_shape::ClipShape;
+#[cfg(feature = "embedded-graphics")]
+pub(crate) use clipped::clip_area;
+pub use clipped::Clipped;
+pub(crate) use clipped::ClippedTarget;
+pub use environment_override::EnvironmentOverride;
+pub use environment_value::EnvironmentValue;
 pub use fixed_frame::FixedFrame;
 pub use flex_frame::FlexFrame;
 pub use foreground_color::ForegroundStyle;
+pub use layout_cache::LayoutCache;
 pub use padding::Padding;
 pub use priority::Priority;
+pub use when::When;
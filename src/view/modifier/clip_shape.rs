@@ -0,0 +1,149 @@
+use crate::{
+    environment::{LayoutEnvironment, RenderEnvironment},
+    layout::{Layout, ResolvedLayout},
+    primitives::{Point, Size},
+    render::CharacterRender,
+    render_target::CharacterRenderTarget,
+    view::Shape,
+};
+
+/// Prevents a child view from drawing outside of a shape fit to its resolved bounds.
+///
+/// Clips compose, so nesting this inside another clip only draws where both allow it.
+pub struct ClipShape<T, S> {
+    child: T,
+    shape: S,
+}
+
+impl<T, S> ClipShape<T, S> {
+    pub fn new(child: T, shape: S) -> Self {
+        Self { child, shape }
+    }
+}
+
+impl<T: PartialEq, S: PartialEq> PartialEq for ClipShape<T, S> {
+    fn eq(&self, other: &Self) -> bool {
+        self.shape == other.shape && self.child == other.child
+    }
+}
+
+impl<V: Layout, S> Layout for ClipShape<V, S> {
+    type Sublayout = V::Sublayout;
+
+    fn layout(&self, offer: Size, env: &impl LayoutEnvironment) -> ResolvedLayout<Self::Sublayout> {
+        self.child.layout(offer, env)
+    }
+
+    fn priority(&self) -> i8 {
+        self.child.priority()
+    }
+
+    fn first_baseline(&self, layout: &ResolvedLayout<Self::Sublayout>) -> Option<u16> {
+        self.child.first_baseline(layout)
+    }
+
+    fn last_baseline(&self, layout: &ResolvedLayout<Self::Sublayout>) -> Option<u16> {
+        self.child.last_baseline(layout)
+    }
+}
+
+/// Discards anything drawn outside of a shape
+struct ShapeTarget<'a, T, S> {
+    target: &'a mut T,
+    shape: &'a S,
+    origin: Point,
+    size: Size,
+}
+
+impl<T, S: Shape> ShapeTarget<'_, T, S> {
+    fn contains(&self, point: Point) -> bool {
+        let relative = Point::new(point.x - self.origin.x, point.y - self.origin.y);
+        self.shape.contains(relative, self.size)
+    }
+}
+
+impl<T: CharacterRenderTarget, S: Shape> CharacterRenderTarget for ShapeTarget<'_, T, S> {
+    type Color = T::Color;
+
+    fn size(&self) -> Size {
+        self.target.size()
+    }
+
+    fn draw(&mut self, point: Point, character: char, color: Self::Color) {
+        if self.contains(point) {
+            self.target.draw(point, character, color);
+        }
+    }
+}
+
+impl<Pixel: Copy, View: Layout, S: Shape> CharacterRender<Pixel> for ClipShape<View, S>
+where
+    View: CharacterRender<Pixel>,
+{
+    fn render(
+        &self,
+        target: &mut impl CharacterRenderTarget<Color = Pixel>,
+        layout: &ResolvedLayout<Self::Sublayout>,
+        origin: Point,
+        env: &impl RenderEnvironment<Color = Pixel>,
+    ) {
+        let mut shape_target = ShapeTarget {
+            target,
+            shape: &self.shape,
+            origin,
+            size: layout.resolved_size,
+        };
+        self.child.render(&mut shape_target, layout, origin, env);
+    }
+}
+
+#[cfg(feature = "embedded-graphics")]
+use embedded_graphics::draw_target::DrawTarget;
+
+#[cfg(feature = "embedded-graphics")]
+impl<T: DrawTarget, S> embedded_graphics_core::geometry::Dimensions for ShapeTarget<'_, T, S> {
+    fn bounding_box(&self) -> embedded_graphics::primitives::Rectangle {
+        self.target.bounding_box()
+    }
+}
+
+#[cfg(feature = "embedded-graphics")]
+impl<T: DrawTarget, S: Shape> DrawTarget for ShapeTarget<'_, T, S> {
+    type Color = T::Color;
+    type Error = T::Error;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = embedded_graphics::Pixel<Self::Color>>,
+    {
+        let shape = self.shape;
+        let (origin, size) = (self.origin, self.size);
+        self.target.draw_iter(pixels.into_iter().filter(|pixel| {
+            let point = Point::from(pixel.0);
+            shape.contains(Point::new(point.x - origin.x, point.y - origin.y), size)
+        }))
+    }
+}
+
+#[cfg(feature = "embedded-graphics")]
+impl<Pixel, View: Layout, S: Shape> crate::render::PixelRender<Pixel> for ClipShape<View, S>
+where
+    View: crate::render::PixelRender<Pixel>,
+    Pixel: embedded_graphics_core::pixelcolor::PixelColor,
+{
+    fn render(
+        &self,
+        target: &mut impl DrawTarget<Color = Pixel>,
+        layout: &ResolvedLayout<Self::Sublayout>,
+        origin: Point,
+        env: &impl RenderEnvironment<Color = Pixel>,
+    ) {
+        let mut shape_target = ShapeTarget {
+            target,
+            shape: &self.shape,
+            origin,
+            size: layout.resolved_size,
+        };
+        self.child.render(&mut shape_target, layout, origin, env);
+    }
+}
@@ -0,0 +1,171 @@
+use crate::{
+    environment::{LayoutEnvironment, RenderEnvironment},
+    layout::{Layout, ResolvedLayout},
+    primitives::{Point, Size},
+    render::CharacterRender,
+    render_target::CharacterRenderTarget,
+};
+
+/// Prevents a child view from drawing outside of its resolved bounds.
+///
+/// Each axis can be clipped independently, allowing content to overflow along the other.
+pub struct Clipped<T> {
+    child: T,
+    horizontal: bool,
+    vertical: bool,
+}
+
+impl<T> Clipped<T> {
+    pub fn new(child: T, horizontal: bool, vertical: bool) -> Self {
+        Self {
+            child,
+            horizontal,
+            vertical,
+        }
+    }
+}
+
+impl<T: PartialEq> PartialEq for Clipped<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.horizontal == other.horizontal
+            && self.vertical == other.vertical
+            && self.child == other.child
+    }
+}
+
+impl<V: Layout> Layout for Clipped<V> {
+    type Sublayout = V::Sublayout;
+
+    fn layout(&self, offer: Size, env: &impl LayoutEnvironment) -> ResolvedLayout<Self::Sublayout> {
+        self.child.layout(offer, env)
+    }
+
+    fn priority(&self) -> i8 {
+        self.child.priority()
+    }
+
+    fn first_baseline(&self, layout: &ResolvedLayout<Self::Sublayout>) -> Option<u16> {
+        self.child.first_baseline(layout)
+    }
+
+    fn last_baseline(&self, layout: &ResolvedLayout<Self::Sublayout>) -> Option<u16> {
+        self.child.last_baseline(layout)
+    }
+}
+
+/// Discards characters drawn outside the clipped axes of a region
+pub(crate) struct ClippedTarget<'a, T> {
+    target: &'a mut T,
+    origin: Point,
+    size: Size,
+    horizontal: bool,
+    vertical: bool,
+}
+
+impl<'a, T> ClippedTarget<'a, T> {
+    pub(crate) fn new(
+        target: &'a mut T,
+        origin: Point,
+        size: Size,
+        horizontal: bool,
+        vertical: bool,
+    ) -> Self {
+        Self {
+            target,
+            origin,
+            size,
+            horizontal,
+            vertical,
+        }
+    }
+}
+
+/// The area of a region along its clipped axes, spanning the entire coordinate space along the
+/// others
+#[cfg(feature = "embedded-graphics")]
+pub(crate) fn clip_area(
+    origin: Point,
+    size: Size,
+    horizontal: bool,
+    vertical: bool,
+) -> embedded_graphics::primitives::Rectangle {
+    let unbounded = (i16::MIN as i32, u16::MAX as u32 + 1);
+    let (x, width) = if horizontal {
+        (origin.x as i32, size.width as u32)
+    } else {
+        unbounded
+    };
+    let (y, height) = if vertical {
+        (origin.y as i32, size.height as u32)
+    } else {
+        unbounded
+    };
+    embedded_graphics::primitives::Rectangle::new(
+        embedded_graphics_core::geometry::Point::new(x, y),
+        embedded_graphics_core::geometry::Size::new(width, height),
+    )
+}
+
+impl<T: CharacterRenderTarget> CharacterRenderTarget for ClippedTarget<'_, T> {
+    type Color = T::Color;
+
+    fn size(&self) -> Size {
+        self.target.size()
+    }
+
+    fn draw(&mut self, point: Point, character: char, color: Self::Color) {
+        let x = point.x - self.origin.x;
+        let y = point.y - self.origin.y;
+        if self.horizontal && !(0..self.size.width as i16).contains(&x) {
+            return;
+        }
+        if self.vertical && !(0..self.size.height as i16).contains(&y) {
+            return;
+        }
+        self.target.draw(point, character, color);
+    }
+}
+
+impl<Pixel: Copy, View: Layout> CharacterRender<Pixel> for Clipped<View>
+where
+    View: CharacterRender<Pixel>,
+{
+    fn render(
+        &self,
+        target: &mut impl CharacterRenderTarget<Color = Pixel>,
+        layout: &ResolvedLayout<Self::Sublayout>,
+        origin: Point,
+        env: &impl RenderEnvironment<Color = Pixel>,
+    ) {
+        let mut clipped_target = ClippedTarget::new(
+            target,
+            origin,
+            layout.resolved_size,
+            self.horizontal,
+            self.vertical,
+        );
+        self.child.render(&mut clipped_target, layout, origin, env);
+    }
+}
+
+#[cfg(feature = "embedded-graphics")]
+use embedded_graphics::{draw_target::DrawTarget, draw_target::DrawTargetExt as _};
+
+#[cfg(feature = "embedded-graphics")]
+impl<Pixel, View: Layout> crate::render::PixelRender<Pixel> for Clipped<View>
+where
+    View: crate::render::PixelRender<Pixel>,
+    Pixel: embedded_graphics_core::pixelcolor::PixelColor,
+{
+    fn render(
+        &self,
+        target: &mut impl DrawTarget<Color = Pixel>,
+        layout: &ResolvedLayout<Self::Sublayout>,
+        origin: Point,
+        env: &impl RenderEnvironment<Color = Pixel>,
+    ) {
+        let area = clip_area(origin, layout.resolved_size, self.horizontal, self.vertical);
+        self.child
+            .render(&mut target.clipped(&area), layout, origin, env);
+    }
+}
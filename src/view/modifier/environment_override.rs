@@ -0,0 +1,118 @@
+use crate::{
+    environment::{LayoutEnvironment, LayoutValues, RenderEnvironment},
+    layout::{Alignment, Layout, LayoutDirection, ResolvedLayout},
+    primitives::{Point, Size},
+    render::CharacterRender,
+    render_target::CharacterRenderTarget,
+};
+
+/// Modifies the environment values seen by a child view
+pub struct EnvironmentOverride<V, F> {
+    child: V,
+    modify: F,
+}
+
+impl<V, F: Fn(&mut LayoutValues)> EnvironmentOverride<V, F> {
+    pub fn new(child: V, modify: F) -> Self {
+        Self { child, modify }
+    }
+
+    fn environment<'a, E: LayoutEnvironment>(&self, env: &'a E) -> OverrideEnv<'a, E> {
+        let mut values = LayoutValues::new(env);
+        (self.modify)(&mut values);
+        OverrideEnv {
+            values,
+            wrapped_env: env,
+        }
+    }
+}
+
+impl<V: PartialEq, F> PartialEq for EnvironmentOverride<V, F> {
+    fn eq(&self, other: &Self) -> bool {
+        self.child == other.child
+    }
+}
+
+impl<V: Layout, F: Fn(&mut LayoutValues)> Layout for EnvironmentOverride<V, F> {
+    type Sublayout = V::Sublayout;
+
+    fn layout(&self, offer: Size, env: &impl LayoutEnvironment) -> ResolvedLayout<Self::Sublayout> {
+        self.child.layout(offer, &self.environment(env))
+    }
+
+    fn priority(&self) -> i8 {
+        self.child.priority()
+    }
+
+    fn first_baseline(&self, layout: &ResolvedLayout<Self::Sublayout>) -> Option<u16> {
+        self.child.first_baseline(layout)
+    }
+
+    fn last_baseline(&self, layout: &ResolvedLayout<Self::Sublayout>) -> Option<u16> {
+        self.child.last_baseline(layout)
+    }
+}
+
+impl<Pixel: Copy, V, F: Fn(&mut LayoutValues)> CharacterRender<Pixel> for EnvironmentOverride<V, F>
+where
+    V: CharacterRender<Pixel>,
+{
+    fn render(
+        &self,
+        target: &mut impl CharacterRenderTarget<Color = Pixel>,
+        layout: &ResolvedLayout<Self::Sublayout>,
+        origin: Point,
+        env: &impl RenderEnvironment<Color = Pixel>,
+    ) {
+        self.child
+            .render(target, layout, origin, &self.environment(env));
+    }
+}
+
+#[cfg(feature = "embedded-graphics")]
+use embedded_graphics::draw_target::DrawTarget;
+
+#[cfg(feature = "embedded-graphics")]
+impl<Pixel, V, F: Fn(&mut LayoutValues)> crate::render::PixelRender<Pixel>
+    for EnvironmentOverride<V, F>
+where
+    V: crate::render::PixelRender<Pixel>,
+    Pixel: embedded_graphics_core::pixelcolor::PixelColor,
+{
+    fn render(
+        &self,
+        target: &mut impl DrawTarget<Color = Pixel>,
+        layout: &ResolvedLayout<Self::Sublayout>,
+        origin: Point,
+        env: &impl RenderEnvironment<Color = Pixel>,
+    ) {
+        self.child
+            .render(target, layout, origin, &self.environment(env));
+    }
+}
+
+struct OverrideEnv<'a, Env> {
+    values: LayoutValues,
+    wrapped_env: &'a Env,
+}
+
+impl<E: LayoutEnvironment> LayoutEnvironment for OverrideEnv<'_, E> {
+    fn layout_direction(&self) -> LayoutDirection {
+        self.values.layout_direction
+    }
+
+    fn alignment(&self) -> Alignment {
+        self.values.alignment
+    }
+
+    fn value<V: 'static>(&self) -> Option<&V> {
+        self.wrapped_env.value()
+    }
+}
+
+impl<E: RenderEnvironment> RenderEnvironment for OverrideEnv<'_, E> {
+    type Color = E::Color;
+    fn foreground_color(&self) -> Self::Color {
+        self.wrapped_env.foreground_color()
+    }
+}
@@ -0,0 +1,121 @@
+use core::any::Any;
+
+use crate::{
+    environment::{LayoutEnvironment, RenderEnvironment},
+    layout::{Alignment, Layout, LayoutDirection, ResolvedLayout},
+    primitives::{Point, Size},
+    render::CharacterRender,
+    render_target::CharacterRenderTarget,
+};
+
+/// Makes a value available to a child view and all of its descendants through
+/// [`LayoutEnvironment::value`], replacing any value of the same type set by an ancestor
+pub struct EnvironmentValue<V, T> {
+    child: V,
+    value: T,
+}
+
+impl<V, T: 'static> EnvironmentValue<V, T> {
+    pub fn new(child: V, value: T) -> Self {
+        Self { child, value }
+    }
+
+    fn environment<'a, E>(&'a self, env: &'a E) -> ValueEnv<'a, E, T> {
+        ValueEnv {
+            value: &self.value,
+            wrapped_env: env,
+        }
+    }
+}
+
+impl<V: PartialEq, T: PartialEq> PartialEq for EnvironmentValue<V, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value && self.child == other.child
+    }
+}
+
+impl<V: Layout, T: 'static> Layout for EnvironmentValue<V, T> {
+    type Sublayout = V::Sublayout;
+
+    fn layout(&self, offer: Size, env: &impl LayoutEnvironment) -> ResolvedLayout<Self::Sublayout> {
+        self.child.layout(offer, &self.environment(env))
+    }
+
+    fn priority(&self) -> i8 {
+        self.child.priority()
+    }
+
+    fn first_baseline(&self, layout: &ResolvedLayout<Self::Sublayout>) -> Option<u16> {
+        self.child.first_baseline(layout)
+    }
+
+    fn last_baseline(&self, layout: &ResolvedLayout<Self::Sublayout>) -> Option<u16> {
+        self.child.last_baseline(layout)
+    }
+}
+
+impl<Pixel: Copy, View: Layout, T: 'static> CharacterRender<Pixel> for EnvironmentValue<View, T>
+where
+    View: CharacterRender<Pixel>,
+{
+    fn render(
+        &self,
+        target: &mut impl CharacterRenderTarget<Color = Pixel>,
+        layout: &ResolvedLayout<Self::Sublayout>,
+        origin: Point,
+        env: &impl RenderEnvironment<Color = Pixel>,
+    ) {
+        self.child
+            .render(target, layout, origin, &self.environment(env));
+    }
+}
+
+#[cfg(feature = "embedded-graphics")]
+use embedded_graphics::draw_target::DrawTarget;
+
+#[cfg(feature = "embedded-graphics")]
+impl<Pixel, View: Layout, T: 'static> crate::render::PixelRender<Pixel>
+    for EnvironmentValue<View, T>
+where
+    View: crate::render::PixelRender<Pixel>,
+    Pixel: embedded_graphics_core::pixelcolor::PixelColor,
+{
+    fn render(
+        &self,
+        target: &mut impl DrawTarget<Color = Pixel>,
+        layout: &ResolvedLayout<Self::Sublayout>,
+        origin: Point,
+        env: &impl RenderEnvironment<Color = Pixel>,
+    ) {
+        self.child
+            .render(target, layout, origin, &self.environment(env));
+    }
+}
+
+struct ValueEnv<'a, Env, T> {
+    value: &'a T,
+    wrapped_env: &'a Env,
+}
+
+impl<E: LayoutEnvironment, T: 'static> LayoutEnvironment for ValueEnv<'_, E, T> {
+    fn layout_direction(&self) -> LayoutDirection {
+        self.wrapped_env.layout_direction()
+    }
+
+    fn alignment(&self) -> Alignment {
+        self.wrapped_env.alignment()
+    }
+
+    fn value<V: 'static>(&self) -> Option<&V> {
+        (self.value as &dyn Any)
+            .downcast_ref()
+            .or_else(|| self.wrapped_env.value())
+    }
+}
+
+impl<E: RenderEnvironment, T: 'static> RenderEnvironment for ValueEnv<'_, E, T> {
+    type Color = E::Color;
+    fn foreground_color(&self) -> Self::Color {
+        self.wrapped_env.foreground_color()
+    }
+}
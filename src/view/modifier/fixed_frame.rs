@@ -7,8 +7,8 @@ use crate::{
 };
 
 pub struct FixedFrame<T> {
-    width: Option<u16>,
-    height: Option<u16>,
+    width: Option<Length>,
+    height: Option<Length>,
     horizontal_alignment: Option<HorizontalAlignment>,
     vertical_alignment: Option<VerticalAlignment>,
     child: T,
@@ -23,8 +23,25 @@ impl<T> FixedFrame<T> {
         vertical_alignment: Option<VerticalAlignment>,
     ) -> Self {
         Self {
-            width,
-            height,
+            width: width.map(Length::Points),
+            height: height.map(Length::Points),
+            horizontal_alignment,
+            vertical_alignment,
+            child,
+        }
+    }
+
+    /// A frame sized to a fraction of the offer along each axis, where 1.0 is the full offer
+    pub fn fractional(
+        child: T,
+        width: Option<f32>,
+        height: Option<f32>,
+        horizontal_alignment: Option<HorizontalAlignment>,
+        vertical_alignment: Option<VerticalAlignment>,
+    ) -> Self {
+        Self {
+            width: width.map(Length::Fraction),
+            height: height.map(Length::Fraction),
             horizontal_alignment,
             vertical_alignment,
             child,
@@ -32,6 +49,21 @@ impl<T> FixedFrame<T> {
     }
 }
 
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Length {
+    Points(u16),
+    Fraction(f32),
+}
+
+impl Length {
+    fn resolve(self, offer: u16) -> u16 {
+        match self {
+            Length::Points(points) => points,
+            Length::Fraction(fraction) => (offer as f32 * fraction) as u16,
+        }
+    }
+}
+
 impl<T> PartialEq for FixedFrame<T> {
     fn eq(&self, other: &Self) -> bool {
         self.width == other.width
@@ -45,14 +77,14 @@ impl<V: Layout> Layout for FixedFrame<V> {
     type Sublayout = ResolvedLayout<V::Sublayout>;
 
     fn layout(&self, offer: Size, env: &impl LayoutEnvironment) -> ResolvedLayout<Self::Sublayout> {
-        let modified_offer = Size::new(
-            self.width.unwrap_or(offer.width),
-            self.height.unwrap_or(offer.height),
-        );
+        let width = self.width.map(|width| width.resolve(offer.width));
+        let height = self.height.map(|height| height.resolve(offer.height));
+        let modified_offer =
+            Size::new(width.unwrap_or(offer.width), height.unwrap_or(offer.height));
         let child_layout = self.child.layout(modified_offer, env);
         let resolved_size = Size::new(
-            self.width.unwrap_or(child_layout.resolved_size.width),
-            self.height.unwrap_or(child_layout.resolved_size.height),
+            width.unwrap_or(child_layout.resolved_size.width),
+            height.unwrap_or(child_layout.resolved_size.height),
         );
         ResolvedLayout {
             sublayouts: child_layout,
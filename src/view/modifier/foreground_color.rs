@@ -29,6 +29,14 @@ impl<Inner: Layout, Color: Copy> Layout for ForegroundStyle<Inner, Color> {
         };
         self.inner.layout(offer, &modified_env)
     }
+
+    fn first_baseline(&self, layout: &ResolvedLayout<Self::Sublayout>) -> Option<u16> {
+        self.inner.first_baseline(layout)
+    }
+
+    fn last_baseline(&self, layout: &ResolvedLayout<Self::Sublayout>) -> Option<u16> {
+        self.inner.last_baseline(layout)
+    }
 }
 
 impl<Color: Copy, Inner> CharacterRender<Color> for ForegroundStyle<Inner, Color>
@@ -89,6 +97,10 @@ impl<E: LayoutEnvironment, C: Copy> LayoutEnvironment for ForegroundStyleEnv<'_,
     fn alignment(&self) -> crate::layout::Alignment {
         self.wrapped_env.alignment()
     }
+
+    fn value<V: 'static>(&self) -> Option<&V> {
+        self.wrapped_env.value()
+    }
 }
 
 impl<E: RenderEnvironment<Color = Color>, Color: Copy> RenderEnvironment
@@ -0,0 +1,129 @@
+use core::cell::RefCell;
+
+use crate::{
+    environment::{LayoutEnvironment, RenderEnvironment},
+    layout::{Alignment, Layout, LayoutDirection, ResolvedLayout},
+    primitives::{Point, Size},
+    render::CharacterRender,
+    render_target::CharacterRenderTarget,
+};
+
+/// The number of recent layouts retained. Stacks typically offer a child a few different
+/// sizes while resolving a single layout pass.
+const CAPACITY: usize = 4;
+
+/// Memoizes the layout of a child view.
+///
+/// Recent layouts are reused when the view is offered the same size with the same layout
+/// direction and alignment. Values injected with the
+/// [`environment`](crate::view::LayoutExtensions::environment) modifier are not part of the key,
+/// so a cached view reading them keeps its old layout when they change.
+///
+/// Views are usually rebuilt whenever their inputs change, which also discards the cache. A view
+/// which is kept between frames must call [`LayoutCache::clear`] when its content or the
+/// environment values it reads change.
+pub struct LayoutCache<T: Layout> {
+    child: T,
+    cache: RefCell<heapless::Deque<CacheEntry<T::Sublayout>, CAPACITY>>,
+}
+
+struct CacheEntry<C: Clone + PartialEq> {
+    offer: Size,
+    direction: LayoutDirection,
+    alignment: Alignment,
+    layout: ResolvedLayout<C>,
+}
+
+impl<T: Layout> LayoutCache<T> {
+    pub fn new(child: T) -> Self {
+        Self {
+            child,
+            cache: RefCell::new(heapless::Deque::new()),
+        }
+    }
+
+    /// Discards the cached layouts, for use when the child's content has changed
+    pub fn clear(&self) {
+        self.cache.borrow_mut().clear();
+    }
+}
+
+impl<T: Layout + PartialEq> PartialEq for LayoutCache<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.child == other.child
+    }
+}
+
+impl<V: Layout> Layout for LayoutCache<V> {
+    type Sublayout = V::Sublayout;
+
+    fn layout(&self, offer: Size, env: &impl LayoutEnvironment) -> ResolvedLayout<Self::Sublayout> {
+        let direction = env.layout_direction();
+        let alignment = env.alignment();
+        if let Some(entry) = self.cache.borrow().iter().find(|entry| {
+            entry.offer == offer && entry.direction == direction && entry.alignment == alignment
+        }) {
+            return entry.layout.clone();
+        }
+
+        let layout = self.child.layout(offer, env);
+        let mut cache = self.cache.borrow_mut();
+        if cache.is_full() {
+            cache.pop_front();
+        }
+        _ = cache.push_back(CacheEntry {
+            offer,
+            direction,
+            alignment,
+            layout: layout.clone(),
+        });
+        layout
+    }
+
+    fn priority(&self) -> i8 {
+        self.child.priority()
+    }
+
+    fn first_baseline(&self, layout: &ResolvedLayout<Self::Sublayout>) -> Option<u16> {
+        self.child.first_baseline(layout)
+    }
+
+    fn last_baseline(&self, layout: &ResolvedLayout<Self::Sublayout>) -> Option<u16> {
+        self.child.last_baseline(layout)
+    }
+}
+
+impl<Pixel: Copy, View: Layout> CharacterRender<Pixel> for LayoutCache<View>
+where
+    View: CharacterRender<Pixel>,
+{
+    fn render(
+        &self,
+        target: &mut impl CharacterRenderTarget<Color = Pixel>,
+        layout: &ResolvedLayout<Self::Sublayout>,
+        origin: Point,
+        env: &impl RenderEnvironment<Color = Pixel>,
+    ) {
+        self.child.render(target, layout, origin, env);
+    }
+}
+
+#[cfg(feature = "embedded-graphics")]
+use embedded_graphics::draw_target::DrawTarget;
+
+#[cfg(feature = "embedded-graphics")]
+impl<Pixel, View: Layout> crate::render::PixelRender<Pixel> for LayoutCache<View>
+where
+    View: crate::render::PixelRender<Pixel>,
+    Pixel: embedded_graphics_core::pixelcolor::PixelColor,
+{
+    fn render(
+        &self,
+        target: &mut impl DrawTarget<Color = Pixel>,
+        layout: &ResolvedLayout<Self::Sublayout>,
+        origin: Point,
+        env: &impl RenderEnvironment<Color = Pixel>,
+    ) {
+        self.child.render(target, layout, origin, env);
+    }
+}
@@ -42,6 +42,16 @@ impl<V: Layout> Layout for Padding<V> {
             resolved_size: padding_size,
         }
     }
+
+    fn first_baseline(&self, layout: &ResolvedLayout<Self::Sublayout>) -> Option<u16> {
+        let baseline = self.child.first_baseline(&layout.sublayouts)?;
+        Some(baseline + self.padding)
+    }
+
+    fn last_baseline(&self, layout: &ResolvedLayout<Self::Sublayout>) -> Option<u16> {
+        let baseline = self.child.last_baseline(&layout.sublayouts)?;
+        Some(baseline + self.padding)
+    }
 }
 
 impl<Pixel: Copy, View: Layout> CharacterRender<Pixel> for Padding<View>
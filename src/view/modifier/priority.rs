@@ -24,6 +24,14 @@ impl<V: Layout> Layout for Priority<V> {
     fn layout(&self, offer: Size, env: &impl LayoutEnvironment) -> ResolvedLayout<Self::Sublayout> {
         self.child.layout(offer, env)
     }
+
+    fn first_baseline(&self, layout: &ResolvedLayout<Self::Sublayout>) -> Option<u16> {
+        self.child.first_baseline(layout)
+    }
+
+    fn last_baseline(&self, layout: &ResolvedLayout<Self::Sublayout>) -> Option<u16> {
+        self.child.last_baseline(layout)
+    }
 }
 
 impl<Pixel: Copy, View: Layout> CharacterRender<Pixel> for Priority<View>
@@ -0,0 +1,127 @@
+use crate::{
+    environment::{LayoutEnvironment, RenderEnvironment},
+    layout::{Layout, ResolvedLayout},
+    primitives::{Point, Size},
+    render::CharacterRender,
+    render_target::CharacterRenderTarget,
+    view::conditional_view::ConditionalViewLayout,
+};
+
+/// A view with modifiers that were only applied when a condition held
+#[derive(Debug, Clone, PartialEq)]
+pub enum When<M, V> {
+    Modified(M),
+    Unmodified(V),
+}
+
+impl<M: Layout, V: Layout> Layout for When<M, V> {
+    type Sublayout =
+        ConditionalViewLayout<ResolvedLayout<M::Sublayout>, ResolvedLayout<V::Sublayout>>;
+
+    fn layout(&self, offer: Size, env: &impl LayoutEnvironment) -> ResolvedLayout<Self::Sublayout> {
+        match self {
+            When::Modified(view) => {
+                let child_layout = view.layout(offer, env);
+                let resolved_size = child_layout.resolved_size;
+                ResolvedLayout {
+                    sublayouts: ConditionalViewLayout::TrueLayout(child_layout),
+                    resolved_size,
+                }
+            }
+            When::Unmodified(view) => {
+                let child_layout = view.layout(offer, env);
+                let resolved_size = child_layout.resolved_size;
+                ResolvedLayout {
+                    sublayouts: ConditionalViewLayout::FalseLayout(child_layout),
+                    resolved_size,
+                }
+            }
+        }
+    }
+
+    fn priority(&self) -> i8 {
+        match self {
+            When::Modified(view) => view.priority(),
+            When::Unmodified(view) => view.priority(),
+        }
+    }
+
+    fn first_baseline(&self, layout: &ResolvedLayout<Self::Sublayout>) -> Option<u16> {
+        match (self, &layout.sublayouts) {
+            (When::Modified(view), ConditionalViewLayout::TrueLayout(layout)) => {
+                view.first_baseline(layout)
+            }
+            (When::Unmodified(view), ConditionalViewLayout::FalseLayout(layout)) => {
+                view.first_baseline(layout)
+            }
+            _ => None,
+        }
+    }
+
+    fn last_baseline(&self, layout: &ResolvedLayout<Self::Sublayout>) -> Option<u16> {
+        match (self, &layout.sublayouts) {
+            (When::Modified(view), ConditionalViewLayout::TrueLayout(layout)) => {
+                view.last_baseline(layout)
+            }
+            (When::Unmodified(view), ConditionalViewLayout::FalseLayout(layout)) => {
+                view.last_baseline(layout)
+            }
+            _ => None,
+        }
+    }
+}
+
+impl<Pixel: Copy, M, V> CharacterRender<Pixel> for When<M, V>
+where
+    M: CharacterRender<Pixel>,
+    V: CharacterRender<Pixel>,
+{
+    fn render(
+        &self,
+        target: &mut impl CharacterRenderTarget<Color = Pixel>,
+        layout: &ResolvedLayout<Self::Sublayout>,
+        origin: Point,
+        env: &impl RenderEnvironment<Color = Pixel>,
+    ) {
+        match (self, &layout.sublayouts) {
+            (When::Modified(view), ConditionalViewLayout::TrueLayout(layout)) => {
+                view.render(target, layout, origin, env)
+            }
+            (When::Unmodified(view), ConditionalViewLayout::FalseLayout(layout)) => {
+                view.render(target, layout, origin, env)
+            }
+            // The layout came from the other branch
+            _ => {}
+        }
+    }
+}
+
+#[cfg(feature = "embedded-graphics")]
+use embedded_graphics::draw_target::DrawTarget;
+
+#[cfg(feature = "embedded-graphics")]
+impl<Pixel, M, V> crate::render::PixelRender<Pixel> for When<M, V>
+where
+    M: crate::render::PixelRender<Pixel>,
+    V: crate::render::PixelRender<Pixel>,
+    Pixel: embedded_graphics_core::pixelcolor::PixelColor,
+{
+    fn render(
+        &self,
+        target: &mut impl DrawTarget<Color = Pixel>,
+        layout: &ResolvedLayout<Self::Sublayout>,
+        origin: Point,
+        env: &impl RenderEnvironment<Color = Pixel>,
+    ) {
+        match (self, &layout.sublayouts) {
+            (When::Modified(view), ConditionalViewLayout::TrueLayout(layout)) => {
+                view.render(target, layout, origin, env)
+            }
+            (When::Unmodified(view), ConditionalViewLayout::FalseLayout(layout)) => {
+                view.render(target, layout, origin, env)
+            }
+            // The layout came from the other branch
+            _ => {}
+        }
+    }
+}
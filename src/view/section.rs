@@ -0,0 +1,241 @@
+use crate::{
+    environment::{LayoutEnvironment, RenderEnvironment},
+    layout::{HorizontalAlignment, Layout, ResolvedLayout},
+    primitives::{Point, Size},
+    render::CharacterRender,
+    render_target::CharacterRenderTarget,
+    view::{EmptyView, VStack},
+};
+
+#[cfg(feature = "embedded-graphics")]
+use embedded_graphics::draw_target::DrawTarget;
+
+/// A group of rows with an optional header and footer, stacked vertically.
+///
+/// Sections are typically placed in a [`VStack`] to build grouped lists, with a [`ForEach`]
+/// providing the rows of each section.
+///
+/// [`ForEach`]: crate::view::ForEach
+pub struct Section<H, C, F> {
+    stack: VStack<(Slot<H>, C, Slot<F>)>,
+}
+
+impl<C> Section<EmptyView, C, EmptyView> {
+    pub fn new(content: C) -> Self {
+        Self {
+            stack: VStack::new((Slot::absent(true), content, Slot::absent(false))).with_spacing(0),
+        }
+    }
+}
+
+impl<H, C, F> Section<H, C, F> {
+    /// Places a view above the content of the section
+    pub fn header<V>(self, header: V) -> Section<V, C, F> {
+        let (old_header, content, footer) = self.stack.items;
+        Section {
+            stack: VStack::new((old_header.replace(header), content, footer))
+                .with_alignment(self.stack.alignment)
+                .with_spacing(0),
+        }
+    }
+
+    /// Places a view below the content of the section
+    pub fn footer<V>(self, footer: V) -> Section<H, C, V> {
+        let (header, content, old_footer) = self.stack.items;
+        Section {
+            stack: VStack::new((header, content, old_footer.replace(footer)))
+                .with_alignment(self.stack.alignment)
+                .with_spacing(0),
+        }
+    }
+
+    pub fn with_alignment(self, alignment: HorizontalAlignment) -> Self {
+        Self {
+            stack: self.stack.with_alignment(alignment),
+        }
+    }
+
+    /// Sets the spacing between the content and the header and footer.
+    ///
+    /// A section without a header or footer hugs its content on that side.
+    pub fn with_spacing(self, spacing: u16) -> Self {
+        let (header, content, footer) = self.stack.items;
+        Self {
+            stack: VStack::new((
+                header.with_spacing(spacing),
+                content,
+                footer.with_spacing(spacing),
+            ))
+            .with_alignment(self.stack.alignment)
+            .with_spacing(0),
+        }
+    }
+}
+
+impl<H, C, F> PartialEq for Section<H, C, F> {
+    fn eq(&self, other: &Self) -> bool {
+        self.stack == other.stack
+    }
+}
+
+impl<H: Layout, C: Layout, F: Layout> Layout for Section<H, C, F> {
+    type Sublayout = (
+        ResolvedLayout<ResolvedLayout<H::Sublayout>>,
+        ResolvedLayout<C::Sublayout>,
+        ResolvedLayout<ResolvedLayout<F::Sublayout>>,
+    );
+
+    fn layout(&self, offer: Size, env: &impl LayoutEnvironment) -> ResolvedLayout<Self::Sublayout> {
+        self.stack.layout(offer, env)
+    }
+}
+
+/// A header or footer, along with the gap between it and the content of the section.
+///
+/// An absent header or footer is an empty view with no gap.
+struct Slot<V> {
+    view: V,
+    present: bool,
+    /// Whether the slot is a header, with its gap below it, or a footer, with its gap above
+    leading: bool,
+    spacing: u16,
+}
+
+impl Slot<EmptyView> {
+    fn absent(leading: bool) -> Self {
+        Self {
+            view: EmptyView,
+            present: false,
+            leading,
+            spacing: 0,
+        }
+    }
+}
+
+impl<V> Slot<V> {
+    fn replace<U>(self, view: U) -> Slot<U> {
+        Slot {
+            view,
+            present: true,
+            leading: self.leading,
+            spacing: self.spacing,
+        }
+    }
+
+    fn with_spacing(self, spacing: u16) -> Self {
+        Self { spacing, ..self }
+    }
+
+    /// The space between the view and the content
+    fn gap(&self) -> u16 {
+        if self.present {
+            self.spacing
+        } else {
+            0
+        }
+    }
+
+    /// The offset of the view within the slot
+    fn view_offset(&self) -> Point {
+        if self.leading {
+            Point::zero()
+        } else {
+            Point::new(0, self.gap() as i16)
+        }
+    }
+}
+
+impl<V> PartialEq for Slot<V> {
+    fn eq(&self, other: &Self) -> bool {
+        self.present == other.present && self.spacing == other.spacing
+    }
+}
+
+impl<V: Layout> Layout for Slot<V> {
+    type Sublayout = ResolvedLayout<V::Sublayout>;
+
+    fn layout(&self, offer: Size, env: &impl LayoutEnvironment) -> ResolvedLayout<Self::Sublayout> {
+        let gap = self.gap();
+        let view_offer = Size::new(offer.width, offer.height.saturating_sub(gap));
+        let view_layout = self.view.layout(view_offer, env);
+        let resolved_size = Size::new(
+            view_layout.resolved_size.width,
+            view_layout.resolved_size.height.saturating_add(gap),
+        );
+        ResolvedLayout {
+            sublayouts: view_layout,
+            resolved_size,
+        }
+    }
+
+    fn priority(&self) -> i8 {
+        self.view.priority()
+    }
+}
+
+impl<Pixel: Copy, H, C, F> CharacterRender<Pixel> for Section<H, C, F>
+where
+    H: CharacterRender<Pixel>,
+    C: CharacterRender<Pixel>,
+    F: CharacterRender<Pixel>,
+{
+    fn render(
+        &self,
+        target: &mut impl CharacterRenderTarget<Color = Pixel>,
+        layout: &ResolvedLayout<Self::Sublayout>,
+        origin: Point,
+        env: &impl RenderEnvironment<Color = Pixel>,
+    ) {
+        self.stack.render(target, layout, origin, env);
+    }
+}
+
+impl<Pixel: Copy, V: CharacterRender<Pixel>> CharacterRender<Pixel> for Slot<V> {
+    fn render(
+        &self,
+        target: &mut impl CharacterRenderTarget<Color = Pixel>,
+        layout: &ResolvedLayout<Self::Sublayout>,
+        origin: Point,
+        env: &impl RenderEnvironment<Color = Pixel>,
+    ) {
+        self.view
+            .render(target, &layout.sublayouts, origin + self.view_offset(), env);
+    }
+}
+
+#[cfg(feature = "embedded-graphics")]
+impl<Pixel, H, C, F> crate::render::PixelRender<Pixel> for Section<H, C, F>
+where
+    H: crate::render::PixelRender<Pixel>,
+    C: crate::render::PixelRender<Pixel>,
+    F: crate::render::PixelRender<Pixel>,
+    Pixel: embedded_graphics_core::pixelcolor::PixelColor,
+{
+    fn render(
+        &self,
+        target: &mut impl DrawTarget<Color = Pixel>,
+        layout: &ResolvedLayout<Self::Sublayout>,
+        origin: Point,
+        env: &impl RenderEnvironment<Color = Pixel>,
+    ) {
+        self.stack.render(target, layout, origin, env);
+    }
+}
+
+#[cfg(feature = "embedded-graphics")]
+impl<Pixel, V> crate::render::PixelRender<Pixel> for Slot<V>
+where
+    V: crate::render::PixelRender<Pixel>,
+    Pixel: embedded_graphics_core::pixelcolor::PixelColor,
+{
+    fn render(
+        &self,
+        target: &mut impl DrawTarget<Color = Pixel>,
+        layout: &ResolvedLayout<Self::Sublayout>,
+        origin: Point,
+        env: &impl RenderEnvironment<Color = Pixel>,
+    ) {
+        self.view
+            .render(target, &layout.sublayouts, origin + self.view_offset(), env);
+    }
+}
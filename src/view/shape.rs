@@ -6,3 +6,12 @@ pub mod style;
 pub use circle::Circle;
 pub use rectangle::Rectangle;
 pub use rounded_rectangle::RoundedRectangle;
+
+use crate::primitives::{Point, Size};
+
+/// A shape that can be used as a clipping mask
+pub trait Shape {
+    /// Returns true if the point, relative to the top leading corner of a frame with the given
+    /// size, is inside the shape when fit to that frame
+    fn contains(&self, point: Point, size: Size) -> bool;
+}
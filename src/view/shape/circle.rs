@@ -6,6 +6,35 @@ use crate::{
 #[derive(Debug, Copy, Clone, PartialEq, Default)]
 pub struct Circle;
 
+impl super::Shape for Circle {
+    /// The circle is centered in the frame, with a diameter of the shorter dimension
+    fn contains(&self, point: Point, size: Size) -> bool {
+        let diameter = size.width.min(size.height);
+        let top_left = Point::new(
+            ((size.width - diameter) / 2) as i16,
+            ((size.height - diameter) / 2) as i16,
+        );
+        circle_contains(top_left, diameter, point)
+    }
+}
+
+/// Tests whether a point is inside the circle with the given bounding square, using the same
+/// threshold embedded-graphics uses to fill circles
+pub(crate) fn circle_contains(top_left: Point, diameter: u16, point: Point) -> bool {
+    if diameter == 0 {
+        return false;
+    }
+    let diameter = diameter as i32;
+    let dx = top_left.x as i32 * 2 + diameter - 1 - point.x as i32 * 2;
+    let dy = top_left.y as i32 * 2 + diameter - 1 - point.y as i32 * 2;
+    let threshold = if diameter <= 4 {
+        diameter * diameter - diameter / 2
+    } else {
+        diameter * diameter
+    };
+    dx * dx + dy * dy < threshold
+}
+
 impl Layout for Circle {
     type Sublayout = ();
 
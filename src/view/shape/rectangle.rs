@@ -14,6 +14,12 @@ impl Rectangle {
     }
 }
 
+impl super::Shape for Rectangle {
+    fn contains(&self, point: Point, size: Size) -> bool {
+        size.contains(point)
+    }
+}
+
 impl Layout for Rectangle {
     type Sublayout = ();
 
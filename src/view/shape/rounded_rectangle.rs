@@ -14,6 +14,31 @@ impl RoundedRectangle {
     }
 }
 
+impl super::Shape for RoundedRectangle {
+    fn contains(&self, point: Point, size: Size) -> bool {
+        if !size.contains(point) {
+            return false;
+        }
+        let radius = self.corner_radius.min(size.width / 2).min(size.height / 2) as i16;
+        let (width, height) = (size.width as i16, size.height as i16);
+        let corner_x = if point.x < radius {
+            0
+        } else if point.x >= width - radius {
+            width - radius * 2
+        } else {
+            return true;
+        };
+        let corner_y = if point.y < radius {
+            0
+        } else if point.y >= height - radius {
+            height - radius * 2
+        } else {
+            return true;
+        };
+        super::circle::circle_contains(Point::new(corner_x, corner_y), radius as u16 * 2, point)
+    }
+}
+
 impl Layout for RoundedRectangle {
     type Sublayout = ();
 
@@ -11,6 +11,18 @@ pub struct Spacer {
     min_length: u16,
 }
 
+impl Spacer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The smallest length the spacer takes along the stack axis, even when siblings would
+    /// otherwise use the space
+    pub fn min_length(self, min_length: u16) -> Self {
+        Self { min_length }
+    }
+}
+
 impl Layout for Spacer {
     type Sublayout = ();
     fn layout(&self, offer: Size, env: &impl LayoutEnvironment) -> ResolvedLayout<()> {
@@ -1,8 +1,16 @@
 use core::marker::PhantomData;
 
-use wrap::WhitespaceWrap;
+pub use highlight::Highlighted;
+pub use vertical::VerticalText;
+pub use wrap::{WhitespaceWrap, WrappedLine};
+
+use crate::layout::VerticalAlignment;
 
 mod character;
+mod highlight;
+mod lines;
+mod tracking;
+mod vertical;
 mod wrap;
 
 // W is hardcoded to WhitespaceWrap, leaving generic for future fix
@@ -11,6 +19,16 @@ pub struct Text<'a, T, F, W = WhitespaceWrap<'a, F>> {
     pub(crate) text: T,
     pub(crate) font: &'a F,
     pub(crate) alignment: HorizontalTextAlignment,
+    pub(crate) vertical_alignment: Option<VerticalAlignment>,
+    pub(crate) truncation: Option<TruncationMode>,
+    pub(crate) letter_spacing: i16,
+    pub(crate) line_spacing: i16,
+    pub(crate) tab_width: u16,
+    pub(crate) monospaced_digits: bool,
+    #[cfg(feature = "embedded-graphics")]
+    pub(crate) underline: bool,
+    #[cfg(feature = "embedded-graphics")]
+    pub(crate) strikethrough: bool,
     pub(crate) _wrap: PhantomData<W>,
 }
 
@@ -22,6 +40,17 @@ pub enum HorizontalTextAlignment {
     Trailing,
 }
 
+/// Where characters are replaced with an ellipsis when text does not fit in the offered height
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TruncationMode {
+    /// Replace the end of the last visible line
+    Tail,
+    /// Replace the start of the remaining text, keeping its end visible
+    Head,
+    /// Replace the middle of the remaining text, keeping its start and end visible
+    Middle,
+}
+
 impl HorizontalTextAlignment {
     pub(crate) fn align(&self, available: i16, content: i16) -> i16 {
         match self {
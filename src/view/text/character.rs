@@ -1,14 +1,18 @@
 use crate::{
     environment::{LayoutEnvironment, RenderEnvironment},
     font::{CharacterFont, FontLayout},
-    layout::{Layout, ResolvedLayout},
+    layout::{Layout, ResolvedLayout, VerticalAlignment},
     primitives::{Point, Size},
     render::CharacterRender,
     render_target::CharacterRenderTarget,
 };
 use core::marker::PhantomData;
 
-use super::{wrap::WhitespaceWrap, HorizontalTextAlignment, Text};
+use super::{
+    lines::{Line, Lines},
+    tracking::Tracked,
+    HorizontalTextAlignment, Text, TruncationMode,
+};
 
 impl<'a, F> Text<'a, &'a str, F> {
     pub fn str(text: &'a str, font: &'a F) -> Self {
@@ -16,6 +20,16 @@ impl<'a, F> Text<'a, &'a str, F> {
             text,
             font,
             alignment: HorizontalTextAlignment::default(),
+            vertical_alignment: None,
+            truncation: None,
+            letter_spacing: 0,
+            line_spacing: 0,
+            tab_width: 4,
+            monospaced_digits: false,
+            #[cfg(feature = "embedded-graphics")]
+            underline: false,
+            #[cfg(feature = "embedded-graphics")]
+            strikethrough: false,
             _wrap: PhantomData,
         }
     }
@@ -27,6 +41,16 @@ impl<'a, const N: usize, F> Text<'a, heapless::String<N>, F> {
             text,
             font,
             alignment: HorizontalTextAlignment::default(),
+            vertical_alignment: None,
+            truncation: None,
+            letter_spacing: 0,
+            line_spacing: 0,
+            tab_width: 4,
+            monospaced_digits: false,
+            #[cfg(feature = "embedded-graphics")]
+            underline: false,
+            #[cfg(feature = "embedded-graphics")]
+            strikethrough: false,
             _wrap: PhantomData,
         }
     }
@@ -39,12 +63,22 @@ impl<'a, F> Text<'a, String, F> {
             text,
             font,
             alignment: HorizontalTextAlignment::default(),
+            vertical_alignment: None,
+            truncation: None,
+            letter_spacing: 0,
+            line_spacing: 0,
+            tab_width: 4,
+            monospaced_digits: false,
+            #[cfg(feature = "embedded-graphics")]
+            underline: false,
+            #[cfg(feature = "embedded-graphics")]
+            strikethrough: false,
             _wrap: PhantomData,
         }
     }
 }
 
-trait Slice {
+pub(super) trait Slice {
     fn as_slice(&self) -> &str;
 }
 
@@ -74,6 +108,86 @@ impl<'a, T, F> Text<'a, T, F> {
     pub fn multiline_text_alignment(self, alignment: HorizontalTextAlignment) -> Self {
         Text { alignment, ..self }
     }
+
+    /// Aligns the wrapped lines vertically within the offered height.
+    ///
+    /// By default, text hugs the height of its lines. Setting a vertical alignment causes the
+    /// text to claim the full offered height and position the lines within it.
+    pub fn vertical_text_alignment(self, alignment: VerticalAlignment) -> Self {
+        Text {
+            vertical_alignment: Some(alignment),
+            ..self
+        }
+    }
+
+    /// Replaces characters with an ellipsis when the text does not fit in the offered height.
+    ///
+    /// Text that is truncated claims the full offered width.
+    pub fn truncation_mode(self, mode: TruncationMode) -> Self {
+        Text {
+            truncation: Some(mode),
+            ..self
+        }
+    }
+
+    /// Adds spacing between each character, in points.
+    ///
+    /// Negative spacing tightens the text, but never moves a character behind the previous one.
+    pub fn letter_spacing(self, spacing: i16) -> Self {
+        Text {
+            letter_spacing: spacing,
+            ..self
+        }
+    }
+
+    /// Adds spacing between lines, in points, without moving the first line.
+    ///
+    /// Negative spacing moves lines closer together, until they are drawn on top of each other.
+    pub fn line_spacing(self, spacing: i16) -> Self {
+        Text {
+            line_spacing: spacing,
+            ..self
+        }
+    }
+
+    /// Expands tabs to the next multiple of `tab_width` spaces from the start of the line.
+    ///
+    /// Tabs are expanded to 4 spaces by default. A width of zero draws tabs with the font,
+    /// like any other character.
+    pub fn tab_width(self, tab_width: u16) -> Self {
+        Text { tab_width, ..self }
+    }
+
+    /// Gives every ASCII digit the advance of the widest digit, so numbers that change often
+    /// keep their width. Each digit is centered within its advance.
+    pub fn monospaced_digits(self) -> Self {
+        Text {
+            monospaced_digits: true,
+            ..self
+        }
+    }
+
+    /// Draws a rule just below the baseline of each line, in the foreground color.
+    ///
+    /// Decorations are only drawn by pixel render targets.
+    #[cfg(feature = "embedded-graphics")]
+    pub fn underline(self) -> Self {
+        Text {
+            underline: true,
+            ..self
+        }
+    }
+
+    /// Draws a rule through the middle of each line, in the foreground color.
+    ///
+    /// Decorations are only drawn by pixel render targets.
+    #[cfg(feature = "embedded-graphics")]
+    pub fn strikethrough(self) -> Self {
+        Text {
+            strikethrough: true,
+            ..self
+        }
+    }
 }
 
 impl<'a, T: PartialEq, F> PartialEq for Text<'a, T, F> {
@@ -84,6 +198,100 @@ impl<'a, T: PartialEq, F> PartialEq for Text<'a, T, F> {
 
 // TODO: consolidate the layout implementations...this is getting ridiculous
 
+impl<'a, T, F: FontLayout> Text<'a, T, F> {
+    /// The size of the wrapped lines, excluding any space claimed for vertical alignment
+    fn content_size(&self, offer: Size) -> Size
+    where
+        T: Slice,
+    {
+        let line_height = self.font.line_height();
+        let line_advance = self.line_advance();
+        let font = self.tracked_font();
+        let mut lines = self.lines(offer, &font);
+        let mut size = Size::zero();
+        let mut next_line_top: u16 = 0;
+        for line in lines.by_ref() {
+            size.width = core::cmp::max(size.width, font.run_width(line.chars()));
+            size.height = next_line_top + line_height;
+            next_line_top = next_line_top.saturating_add(line_advance);
+            if next_line_top >= offer.height {
+                break;
+            }
+        }
+        if lines.is_truncated() {
+            size.width = offer.width;
+        }
+        size
+    }
+
+    /// The distance from the top of one line to the top of the next
+    fn line_advance(&self) -> u16 {
+        self.font
+            .line_height()
+            .saturating_add_signed(self.line_spacing)
+    }
+
+    pub(super) fn tracked_font(&self) -> Tracked<'a, F> {
+        let font = Tracked::new(self.font, self.letter_spacing, self.tab_width);
+        if self.monospaced_digits {
+            font.monospaced_digits()
+        } else {
+            font
+        }
+    }
+
+    fn lines<'b>(&'b self, available: Size, font: &'b Tracked<'b, F>) -> Lines<'b, Tracked<'b, F>>
+    where
+        T: Slice,
+    {
+        let available = Size::new(font.available_width(available.width), available.height);
+        Lines::new(
+            self.text.as_slice(),
+            available,
+            self.line_advance(),
+            font,
+            self.truncation,
+        )
+        .tab_width(self.tab_width)
+    }
+
+    /// Each visible line, with the offset of its top leading corner from the origin of the text
+    pub(super) fn positioned_lines<'b>(
+        &'b self,
+        size: Size,
+        font: &'b Tracked<'b, F>,
+    ) -> impl Iterator<Item = (Line<'b>, Point)> + 'b
+    where
+        T: Slice,
+    {
+        let y_offset = self.vertical_offset(size) as i32;
+        let line_advance = self.line_advance() as i32;
+        self.lines(size, font)
+            .enumerate()
+            .map(move |(i, line)| (line, i as i32 * line_advance))
+            .take_while(move |(_, top)| *top == 0 || *top < size.height as i32)
+            .map(move |(line, top)| {
+                let width = font.run_width(line.chars());
+                let x = self.alignment.align(size.width as i16, width as i16);
+                (line, Point::new(x, (y_offset + top) as i16))
+            })
+    }
+
+    /// The vertical offset of the first line within the resolved frame
+    fn vertical_offset(&self, resolved_size: Size) -> i16
+    where
+        T: Slice,
+    {
+        match self.vertical_alignment {
+            Some(alignment) => alignment.align(
+                resolved_size.height as i16,
+                self.content_size(resolved_size).height as i16,
+            ),
+            None => 0,
+        }
+    }
+}
+
 impl<'a, T: Slice, F: FontLayout> Layout for Text<'a, T, F> {
     // this could be used to store the precalculated line breaks
     type Sublayout = ();
@@ -99,15 +307,9 @@ impl<'a, T: Slice, F: FontLayout> Layout for Text<'a, T, F> {
                 resolved_size: Size::new(0, 0),
             };
         }
-        let line_height = self.font.line_height();
-        let wrap = WhitespaceWrap::new(self.text.as_slice(), offer.width, self.font);
-        let mut size = Size::zero();
-        for line in wrap {
-            size.width = core::cmp::max(size.width, self.font.str_width(line));
-            size.height += line_height;
-            if size.height >= offer.height {
-                break;
-            }
+        let mut size = self.content_size(offer);
+        if self.vertical_alignment.is_some() {
+            size.height = offer.height;
         }
 
         ResolvedLayout {
@@ -115,6 +317,24 @@ impl<'a, T: Slice, F: FontLayout> Layout for Text<'a, T, F> {
             resolved_size: size,
         }
     }
+
+    fn first_baseline(&self, layout: &ResolvedLayout<Self::Sublayout>) -> Option<u16> {
+        if layout.resolved_size.area() == 0 {
+            return None;
+        }
+        let top = self.vertical_offset(layout.resolved_size);
+        Some(top.saturating_add_unsigned(self.font.baseline()).max(0) as u16)
+    }
+
+    fn last_baseline(&self, layout: &ResolvedLayout<Self::Sublayout>) -> Option<u16> {
+        if layout.resolved_size.area() == 0 {
+            return None;
+        }
+        let content_height = self.content_size(layout.resolved_size).height;
+        let last_line_top = content_height.saturating_sub(self.font.line_height());
+        let top = self.vertical_offset(layout.resolved_size) + last_line_top as i16;
+        Some(top.saturating_add_unsigned(self.font.baseline()).max(0) as u16)
+    }
 }
 
 impl<'a, T: Slice, F: CharacterFont<Color>, Color: Copy> CharacterRender<Color> for Text<'a, T, F> {
@@ -129,27 +349,26 @@ impl<'a, T: Slice, F: CharacterFont<Color>, Color: Copy> CharacterRender<Color>
             return;
         }
 
-        let line_height = self.font.line_height() as i16;
-
-        let mut height = 0;
-        let wrap = WhitespaceWrap::new(self.text.as_slice(), layout.resolved_size.width, self.font);
-        for line in wrap {
+        let font = self.tracked_font();
+        for (line, offset) in self.positioned_lines(layout.resolved_size, &font) {
             let color = env.foreground_color();
-            let width = self.font.str_width(line);
-
-            let x = self
-                .alignment
-                .align(layout.resolved_size.width as i16, width as i16);
-            self.font.render_iter_solid(
-                target,
-                Point::new(origin.x + x, origin.y + height),
-                color,
-                line.chars(),
-            );
-
-            height += line_height;
-            if height >= layout.resolved_size.height as i16 {
-                break;
+            let position = origin + offset;
+            if font.draws_naturally(line.chars()) {
+                self.font
+                    .render_iter_solid(target, position, color, line.chars());
+            } else {
+                let mut x = 0;
+                for character in line.chars() {
+                    if !font.is_tab_stop(character) {
+                        self.font.render_iter_solid(
+                            target,
+                            position + Point::new((x + font.glyph_offset(character)) as i16, 0),
+                            color,
+                            core::iter::once(character),
+                        );
+                    }
+                    x += font.advance(character, x);
+                }
             }
         }
     }
@@ -177,28 +396,55 @@ impl<
             return;
         }
 
-        let line_height = self.font.line_height() as i16;
-
-        let mut height = 0;
-        let wrap = WhitespaceWrap::new(self.text.as_slice(), layout.resolved_size.width, self.font);
-        for line in wrap {
+        let font = self.tracked_font();
+        for (line, offset) in self.positioned_lines(layout.resolved_size, &font) {
             let color = env.foreground_color();
-            let width = self.font.str_width(line);
-
-            let x = self
-                .alignment
-                .align(layout.resolved_size.width as i16, width as i16);
-            self.font.render_iter(
-                target,
-                Point::new(origin.x + x, origin.y + height),
-                color,
-                line.chars(),
-            );
-
-            height += line_height;
-            if height >= layout.resolved_size.height as i16 {
-                break;
+            let width = font.run_width(line.chars());
+            let position = origin + offset;
+            if font.draws_naturally(line.chars()) {
+                self.font.render_iter(target, position, color, line.chars());
+            } else {
+                let mut x = 0;
+                for character in line.chars() {
+                    if !font.is_tab_stop(character) {
+                        self.font.render_iter(
+                            target,
+                            position + Point::new((x + font.glyph_offset(character)) as i16, 0),
+                            color,
+                            core::iter::once(character),
+                        );
+                    }
+                    x += font.advance(character, x);
+                }
+            }
+
+            if self.underline {
+                // Just below the baseline, without leaving the line
+                let y = core::cmp::min(
+                    self.font.baseline() + 1,
+                    self.font.line_height().saturating_sub(1),
+                );
+                draw_rule(target, position + Point::new(0, y as i16), width, color);
+            }
+            if self.strikethrough {
+                let y = self.font.line_height() / 2;
+                draw_rule(target, position + Point::new(0, y as i16), width, color);
             }
         }
     }
 }
+
+/// Draws a horizontal rule one point tall
+#[cfg(feature = "embedded-graphics")]
+fn draw_rule<Color: embedded_graphics_core::pixelcolor::PixelColor>(
+    target: &mut impl DrawTarget<Color = Color>,
+    origin: Point,
+    width: u16,
+    color: Color,
+) {
+    let area = embedded_graphics::primitives::Rectangle::new(
+        origin.into(),
+        embedded_graphics_core::geometry::Size::new(width.into(), 1),
+    );
+    _ = target.fill_solid(&area, color);
+}
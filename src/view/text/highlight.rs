@@ -0,0 +1,146 @@
+use core::ops::Range;
+
+use crate::{
+    environment::{LayoutEnvironment, RenderEnvironment},
+    font::{CharacterFont, FontLayout},
+    layout::{Layout, ResolvedLayout},
+    primitives::{Point, Size},
+    render::CharacterRender,
+    render_target::CharacterRenderTarget,
+};
+
+use super::{character::Slice, Text};
+
+/// Text with a range of characters drawn in a highlight color.
+///
+/// Pixel targets fill the background behind the highlighted characters, while character targets
+/// draw the highlighted characters themselves in the highlight color.
+pub struct Highlighted<'a, T, F, C> {
+    text: Text<'a, T, F>,
+    range: Range<usize>,
+    color: C,
+}
+
+impl<'a, T, F> Text<'a, T, F> {
+    /// Highlights the characters in a byte range of the text, across however many lines they span.
+    ///
+    /// Any character which overlaps the range is highlighted, so ranges which split a multibyte
+    /// character are widened to include it.
+    pub fn highlight<C>(self, range: Range<usize>, color: C) -> Highlighted<'a, T, F, C> {
+        Highlighted {
+            text: self,
+            range,
+            color,
+        }
+    }
+}
+
+impl<T: PartialEq, F, C: PartialEq> PartialEq for Highlighted<'_, T, F, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.range == other.range && self.color == other.color && self.text == other.text
+    }
+}
+
+impl<'a, T, F: FontLayout, C> Highlighted<'a, T, F, C> {
+    /// Calls `f` with the offset, character, and advance of each highlighted character
+    fn for_each_highlighted(&self, size: Size, mut f: impl FnMut(Point, char, u16))
+    where
+        T: Slice,
+    {
+        let font = self.text.tracked_font();
+        let text = self.text.text.as_slice();
+        for (line, offset) in self.text.positioned_lines(size, &font) {
+            let mut x = 0;
+            for (index, character) in line.indexed_chars(text) {
+                let advance = font.advance(character, x);
+                let overlaps = index.is_some_and(|i| {
+                    i < self.range.end && i + character.len_utf8() > self.range.start
+                });
+                if overlaps {
+                    f(offset + Point::new(x as i16, 0), character, advance);
+                }
+                x += advance;
+            }
+        }
+    }
+}
+
+impl<'a, T: Slice, F: FontLayout, C> Layout for Highlighted<'a, T, F, C> {
+    type Sublayout = ();
+
+    fn layout(&self, offer: Size, env: &impl LayoutEnvironment) -> ResolvedLayout<Self::Sublayout> {
+        self.text.layout(offer, env)
+    }
+
+    fn priority(&self) -> i8 {
+        self.text.priority()
+    }
+
+    fn first_baseline(&self, layout: &ResolvedLayout<Self::Sublayout>) -> Option<u16> {
+        self.text.first_baseline(layout)
+    }
+
+    fn last_baseline(&self, layout: &ResolvedLayout<Self::Sublayout>) -> Option<u16> {
+        self.text.last_baseline(layout)
+    }
+}
+
+impl<'a, T: Slice, F: CharacterFont<Color>, Color: Copy> CharacterRender<Color>
+    for Highlighted<'a, T, F, Color>
+{
+    fn render(
+        &self,
+        target: &mut impl CharacterRenderTarget<Color = Color>,
+        layout: &ResolvedLayout<()>,
+        origin: Point,
+        env: &impl RenderEnvironment<Color = Color>,
+    ) {
+        self.text.render(target, layout, origin, env);
+        if layout.resolved_size.area() == 0 {
+            return;
+        }
+
+        let font = self.text.tracked_font();
+        self.for_each_highlighted(layout.resolved_size, |offset, character, _| {
+            if font.is_tab_stop(character) {
+                return;
+            }
+            let position = origin + offset + Point::new(font.glyph_offset(character) as i16, 0);
+            self.text.font.render_iter_solid(
+                target,
+                position,
+                self.color,
+                core::iter::once(character),
+            );
+        });
+    }
+}
+
+#[cfg(feature = "embedded-graphics")]
+impl<
+        'a,
+        T: Slice,
+        F: crate::font::PixelFont<Color>,
+        Color: embedded_graphics_core::pixelcolor::PixelColor,
+    > crate::render::PixelRender<Color> for Highlighted<'a, T, F, Color>
+{
+    fn render(
+        &self,
+        target: &mut impl embedded_graphics::draw_target::DrawTarget<Color = Color>,
+        layout: &ResolvedLayout<()>,
+        origin: Point,
+        env: &impl RenderEnvironment<Color = Color>,
+    ) {
+        if layout.resolved_size.area() != 0 {
+            let line_height = self.text.font.line_height() as u32;
+            self.for_each_highlighted(layout.resolved_size, |offset, _, advance| {
+                let area = embedded_graphics::primitives::Rectangle::new(
+                    (origin + offset).into(),
+                    embedded_graphics_core::geometry::Size::new(advance as u32, line_height),
+                );
+                _ = target.fill_solid(&area, self.color);
+            });
+        }
+        self.text.render(target, layout, origin, env);
+    }
+}
@@ -0,0 +1,295 @@
+use core::iter::Peekable;
+
+use crate::{font::FontLayout, primitives::Size};
+
+use super::{wrap::WhitespaceWrap, TruncationMode};
+
+const ELLIPSIS: char = '…';
+
+/// A line of text to render, optionally with an ellipsis between two runs of characters
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct Line<'a> {
+    leading: &'a str,
+    ellipsis: bool,
+    trailing: &'a str,
+}
+
+impl<'a> Line<'a> {
+    fn plain(content: &'a str) -> Self {
+        Self {
+            leading: content,
+            ellipsis: false,
+            trailing: "",
+        }
+    }
+
+    /// The characters of the line, in the order they should be drawn
+    pub(crate) fn chars(&self) -> impl Iterator<Item = char> + 'a {
+        self.leading
+            .chars()
+            .chain(self.ellipsis.then_some(ELLIPSIS))
+            .chain(self.trailing.chars())
+            .map(displayed)
+    }
+
+    /// The characters of the line with their byte offset in `text`, which must be the string
+    /// the line was taken from. An inserted ellipsis has no offset.
+    pub(crate) fn indexed_chars(
+        &self,
+        text: &'a str,
+    ) -> impl Iterator<Item = (Option<usize>, char)> + 'a {
+        let indexed = move |run: &'a str| {
+            let start = (run.as_ptr() as usize).wrapping_sub(text.as_ptr() as usize);
+            run.char_indices()
+                .map(move |(i, c)| (Some(start + i), displayed(c)))
+        };
+        indexed(self.leading)
+            .chain(self.ellipsis.then_some((None, ELLIPSIS)))
+            .chain(indexed(self.trailing))
+    }
+}
+
+/// Truncated lines can span newlines in the source text, which are shown as spaces
+fn displayed(character: char) -> char {
+    if character == '\n' {
+        ' '
+    } else {
+        character
+    }
+}
+
+/// Wraps text into lines, truncating the last line that fits when text remains
+pub(crate) struct Lines<'a, F: FontLayout> {
+    text: &'a str,
+    wrap: Peekable<WhitespaceWrap<'a, F>>,
+    font: &'a F,
+    available_width: u16,
+    remaining_lines: u16,
+    truncation: Option<TruncationMode>,
+    truncated: bool,
+}
+
+impl<'a, F: FontLayout> Lines<'a, F> {
+    pub(crate) fn new(
+        text: &'a str,
+        available: Size,
+        line_advance: u16,
+        font: &'a F,
+        truncation: Option<TruncationMode>,
+    ) -> Self {
+        // A partially visible line counts as a line that fits
+        let max_lines = match line_advance {
+            0 => u16::MAX,
+            line_advance => available.height.div_ceil(line_advance).max(1),
+        };
+        Self {
+            text,
+            wrap: WhitespaceWrap::new(text, available.width, font).peekable(),
+            font,
+            available_width: available.width,
+            remaining_lines: max_lines,
+            truncation,
+            truncated: false,
+        }
+    }
+
+    /// Expands tabs when wrapping. Must be set before any lines are produced.
+    pub(crate) fn tab_width(self, tab_width: u16) -> Self {
+        Self {
+            wrap: WhitespaceWrap::new(self.text, self.available_width, self.font)
+                .tab_width(tab_width)
+                .peekable(),
+            ..self
+        }
+    }
+
+    /// Whether any lines produced so far were truncated
+    pub(crate) fn is_truncated(&self) -> bool {
+        self.truncated
+    }
+
+    fn truncate(&self, line: &'a str, mode: TruncationMode) -> Line<'a> {
+        let Some(budget) = self
+            .available_width
+            .checked_sub(self.font.character_width(ELLIPSIS))
+        else {
+            // Not even the ellipsis fits
+            return Line::plain("");
+        };
+        match mode {
+            TruncationMode::Tail => Line {
+                leading: self.prefix(line, budget),
+                ellipsis: true,
+                trailing: "",
+            },
+            TruncationMode::Head => Line {
+                leading: "",
+                ellipsis: true,
+                trailing: self.suffix(self.remainder(line), budget),
+            },
+            TruncationMode::Middle => {
+                let remainder = self.remainder(line);
+                let leading = self.prefix(remainder, budget - budget / 2);
+                let trailing = self.suffix(remainder, budget - self.width(leading));
+                Line {
+                    leading,
+                    ellipsis: true,
+                    trailing,
+                }
+            }
+        }
+    }
+
+    /// All the text from the start of the line to the end of the source text
+    fn remainder(&self, line: &'a str) -> &'a str {
+        // Lines produced by the wrap are always slices of the source text
+        let start = line.as_ptr() as usize - self.text.as_ptr() as usize;
+        self.text[start..].trim_end()
+    }
+
+    /// The longest run of characters from the start of the text that fits in the width
+    fn prefix(&self, text: &'a str, available_width: u16) -> &'a str {
+        let mut width = 0;
+        for (i, character) in text.char_indices() {
+            width += self.font.character_width(displayed(character));
+            if width > available_width {
+                return &text[..i];
+            }
+        }
+        text
+    }
+
+    /// The longest run of characters from the end of the text that fits in the width
+    fn suffix(&self, text: &'a str, available_width: u16) -> &'a str {
+        let mut width = 0;
+        for (i, character) in text.char_indices().rev() {
+            width += self.font.character_width(displayed(character));
+            if width > available_width {
+                return &text[i + character.len_utf8()..];
+            }
+        }
+        text
+    }
+
+    fn width(&self, text: &str) -> u16 {
+        text.chars()
+            .map(|c| self.font.character_width(displayed(c)))
+            .sum()
+    }
+}
+
+impl<'a, F: FontLayout> Iterator for Lines<'a, F> {
+    type Item = Line<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let Some(mode) = self.truncation else {
+            return self.wrap.next().map(Line::plain);
+        };
+        if self.remaining_lines == 0 {
+            return None;
+        }
+        let line = self.wrap.next()?;
+        self.remaining_lines -= 1;
+        if self.remaining_lines > 0 || self.wrap.peek().is_none() {
+            return Some(Line::plain(line));
+        }
+
+        self.truncated = true;
+        Some(self.truncate(line, mode))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Line, Lines};
+    use crate::{font::BufferCharacterFont, primitives::Size, view::TruncationMode};
+
+    static FONT: BufferCharacterFont = BufferCharacterFont;
+
+    fn lines(text: &str, available: Size, mode: TruncationMode) -> Vec<String> {
+        Lines::new(text, available, 1, &FONT, Some(mode))
+            .map(|line: Line| line.chars().collect())
+            .collect()
+    }
+
+    #[test]
+    fn text_that_fits_is_not_truncated() {
+        let mut lines = Lines::new(
+            "hello",
+            Size::new(5, 1),
+            1,
+            &FONT,
+            Some(TruncationMode::Tail),
+        );
+        assert_eq!(
+            lines.next().map(|l| l.chars().collect::<String>()),
+            Some("hello".into())
+        );
+        assert_eq!(lines.next(), None);
+        assert!(!lines.is_truncated());
+    }
+
+    #[test]
+    fn tail_truncates_last_visible_line() {
+        assert_eq!(
+            lines("hello world", Size::new(6, 1), TruncationMode::Tail),
+            vec!["hello…"]
+        );
+        assert_eq!(
+            lines("hello world", Size::new(5, 1), TruncationMode::Tail),
+            vec!["hell…"]
+        );
+        assert_eq!(
+            lines("one two three", Size::new(5, 2), TruncationMode::Tail),
+            vec!["one", "two…"]
+        );
+    }
+
+    #[test]
+    fn head_keeps_end_of_text() {
+        assert_eq!(
+            lines("hello world", Size::new(6, 1), TruncationMode::Head),
+            vec!["…world"]
+        );
+        assert_eq!(
+            lines("one two three", Size::new(5, 2), TruncationMode::Head),
+            vec!["one", "…hree"]
+        );
+    }
+
+    #[test]
+    fn middle_keeps_both_ends() {
+        assert_eq!(
+            lines("hello world", Size::new(6, 1), TruncationMode::Middle),
+            vec!["hel…ld"]
+        );
+    }
+
+    #[test]
+    fn newlines_in_remainder_are_shown_as_spaces() {
+        assert_eq!(
+            lines("ab\ncd\nef", Size::new(6, 1), TruncationMode::Middle),
+            vec!["ab …ef"]
+        );
+    }
+
+    #[test]
+    fn ellipsis_that_does_not_fit_produces_empty_line() {
+        assert_eq!(
+            lines("hello", Size::new(0, 1), TruncationMode::Tail),
+            vec![""]
+        );
+    }
+
+    #[test]
+    fn multibyte_characters_on_the_boundary_do_not_panic() {
+        assert_eq!(
+            lines("12°C and falling", Size::new(4, 1), TruncationMode::Tail),
+            vec!["12°…"]
+        );
+        assert_eq!(
+            lines("falling to 12°C", Size::new(4, 1), TruncationMode::Head),
+            vec!["…2°C"]
+        );
+    }
+}
@@ -0,0 +1,178 @@
+use crate::font::FontLayout;
+
+/// Adds a fixed amount of spacing after the advance of every character, and expands tabs to
+/// tab stops.
+///
+/// The advance of a character never drops below zero, even with negative spacing.
+pub(crate) struct Tracked<'a, F> {
+    font: &'a F,
+    spacing: i16,
+    tab_width: u16,
+    digit_width: Option<u16>,
+}
+
+impl<'a, F: FontLayout> Tracked<'a, F> {
+    pub(crate) fn new(font: &'a F, spacing: i16, tab_width: u16) -> Self {
+        Self {
+            font,
+            spacing,
+            tab_width,
+            digit_width: None,
+        }
+    }
+
+    /// Measures every ASCII digit as wide as the widest one, so numbers don't shift as they change
+    pub(crate) fn monospaced_digits(self) -> Self {
+        let digit_width = ('0'..='9').map(|c| self.font.character_width(c)).max();
+        Self {
+            digit_width,
+            ..self
+        }
+    }
+
+    /// The width of a character before spacing is added
+    fn glyph_width(&self, character: char) -> u16 {
+        match self.digit_width {
+            Some(width) if character.is_ascii_digit() => width,
+            _ => self.font.character_width(character),
+        }
+    }
+
+    /// The offset that centers a glyph within a widened digit
+    pub(crate) fn glyph_offset(&self, character: char) -> u16 {
+        (self.glyph_width(character) - self.font.character_width(character)) / 2
+    }
+
+    /// Whether the font can draw these characters at their natural advances, without placing
+    /// each one individually
+    pub(crate) fn draws_naturally(&self, mut characters: impl Iterator<Item = char>) -> bool {
+        self.spacing == 0
+            && characters.all(|c| {
+                !self.is_tab_stop(c) && !(self.digit_width.is_some() && c.is_ascii_digit())
+            })
+    }
+
+    /// The distance between tab stops, or zero when tabs are not expanded
+    fn tab_stop(&self) -> u16 {
+        self.tab_width.saturating_mul(self.character_width(' '))
+    }
+
+    /// Whether the character is a tab that advances to the next tab stop instead of being drawn
+    pub(crate) fn is_tab_stop(&self, character: char) -> bool {
+        character == '\t' && self.tab_width > 0
+    }
+
+    /// The advance of a character that starts at `x` points from the start of the line
+    pub(crate) fn advance(&self, character: char, x: u16) -> u16 {
+        match self.tab_stop() {
+            tab_stop if tab_stop > 0 && character == '\t' => tab_stop - x % tab_stop,
+            _ => self.character_width(character),
+        }
+    }
+
+    /// Widths measured with this font include spacing after the last character. This adjusts
+    /// an available width so a line that fits without that trailing spacing is not wrapped.
+    pub(crate) fn available_width(&self, width: u16) -> u16 {
+        width.saturating_add_signed(self.spacing)
+    }
+
+    /// The width of a run of characters, with spacing between but not after the last character
+    pub(crate) fn run_width(&self, characters: impl Iterator<Item = char>) -> u16 {
+        let mut characters = characters.peekable();
+        let mut width = 0;
+        while let Some(character) = characters.next() {
+            width += if characters.peek().is_some() || self.is_tab_stop(character) {
+                self.advance(character, width)
+            } else {
+                self.glyph_width(character)
+            };
+        }
+        width
+    }
+}
+
+impl<F: FontLayout> FontLayout for Tracked<'_, F> {
+    #[inline]
+    fn line_height(&self) -> u16 {
+        self.font.line_height()
+    }
+
+    /// Tabs are measured as a full tab stop when their position is not known
+    #[inline]
+    fn character_width(&self, character: char) -> u16 {
+        if self.is_tab_stop(character) {
+            return self.tab_stop();
+        }
+        self.glyph_width(character)
+            .saturating_add_signed(self.spacing)
+    }
+
+    #[inline]
+    fn baseline(&self) -> u16 {
+        self.font.baseline()
+    }
+
+    fn str_width(&self, text: &str) -> u16 {
+        self.run_width(text.chars())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Tracked;
+    use crate::font::{BufferCharacterFont, FontLayout};
+
+    #[test]
+    fn spacing_is_added_between_characters() {
+        let font = Tracked::new(&BufferCharacterFont, 2, 0);
+        assert_eq!(font.character_width('a'), 3);
+        assert_eq!(font.str_width("abc"), 7);
+        assert_eq!(font.str_width("a"), 1);
+        assert_eq!(font.str_width(""), 0);
+    }
+
+    #[test]
+    fn negative_spacing_does_not_go_below_zero() {
+        let font = Tracked::new(&BufferCharacterFont, -3, 0);
+        assert_eq!(font.character_width('a'), 0);
+        assert_eq!(font.str_width("abc"), 1);
+    }
+
+    #[test]
+    fn tabs_advance_to_the_next_tab_stop() {
+        let font = Tracked::new(&BufferCharacterFont, 0, 4);
+        assert_eq!(font.str_width("\tab"), 6);
+        assert_eq!(font.str_width("ab\tc"), 5);
+        assert_eq!(font.str_width("abcd\te"), 9);
+        assert_eq!(font.str_width("a\t"), 4);
+    }
+
+    struct NarrowOnes;
+
+    impl FontLayout for NarrowOnes {
+        fn line_height(&self) -> u16 {
+            1
+        }
+
+        fn character_width(&self, character: char) -> u16 {
+            if character == '1' {
+                1
+            } else {
+                3
+            }
+        }
+    }
+
+    #[test]
+    fn monospaced_digits_use_the_widest_digit() {
+        let font = Tracked::new(&NarrowOnes, 1, 0).monospaced_digits();
+        assert_eq!(font.character_width('1'), 4);
+        assert_eq!(font.str_width("11"), 7);
+        assert_eq!(font.glyph_offset('1'), 1);
+        assert_eq!(font.glyph_offset('8'), 0);
+        assert!(!font.draws_naturally("ab".chars()));
+        let font = Tracked::new(&NarrowOnes, 0, 0).monospaced_digits();
+        assert!(font.draws_naturally("ab".chars()));
+        assert!(!font.draws_naturally("a1".chars()));
+    }
+}
@@ -0,0 +1,198 @@
+use core::cmp::max;
+
+use crate::{
+    environment::{LayoutEnvironment, RenderEnvironment},
+    font::{CharacterFont, FontLayout},
+    layout::{Layout, ResolvedLayout},
+    primitives::{Point, Size},
+    render::CharacterRender,
+    render_target::CharacterRenderTarget,
+};
+
+use super::{character::Slice, wrap::WhitespaceWrap, Text};
+
+/// Text with characters stacked from top to bottom.
+///
+/// Lines are wrapped into columns by the available height, and columns are placed from left
+/// to right. Characters are not rotated, so rendering is as cheap as horizontal text. The
+/// multiline text alignment positions each column vertically.
+pub struct VerticalText<'a, T, F> {
+    text: Text<'a, T, F>,
+}
+
+impl<'a, T, F> Text<'a, T, F> {
+    /// Stacks the characters of this text vertically
+    pub fn vertical(self) -> VerticalText<'a, T, F> {
+        VerticalText { text: self }
+    }
+}
+
+impl<'a, T: PartialEq, F> PartialEq for VerticalText<'a, T, F> {
+    fn eq(&self, other: &Self) -> bool {
+        self.text == other.text
+    }
+}
+
+/// Measures every character by the vertical advance of the font, so columns can be wrapped
+/// using the same logic as lines.
+struct VerticalMetrics<'a, F>(&'a F);
+
+impl<F: FontLayout> FontLayout for VerticalMetrics<'_, F> {
+    fn line_height(&self) -> u16 {
+        self.0.line_height()
+    }
+
+    fn character_width(&self, _: char) -> u16 {
+        self.0.line_height()
+    }
+}
+
+impl<'a, T, F: FontLayout> VerticalText<'a, T, F> {
+    /// The width of a column is the width of its widest character
+    fn column_width(&self, column: &str) -> u16 {
+        column
+            .chars()
+            .map(|c| self.text.font.character_width(c))
+            .max()
+            .unwrap_or_else(|| self.text.font.character_width(' '))
+    }
+}
+
+impl<'a, T: Slice, F: FontLayout> Layout for VerticalText<'a, T, F> {
+    type Sublayout = ();
+
+    fn layout(
+        &self,
+        offer: Size,
+        _env: &impl LayoutEnvironment,
+    ) -> ResolvedLayout<Self::Sublayout> {
+        if offer.area() == 0 {
+            return ResolvedLayout {
+                sublayouts: (),
+                resolved_size: Size::zero(),
+            };
+        }
+        let metrics = VerticalMetrics(self.text.font);
+        let wrap = WhitespaceWrap::new(self.text.text.as_slice(), offer.height, &metrics);
+        let mut size = Size::zero();
+        for column in wrap {
+            // Columns which would overflow the offered width are dropped
+            let width = size.width.saturating_add(self.column_width(column));
+            if width > offer.width {
+                break;
+            }
+            size.width = width;
+            size.height = max(size.height, metrics.str_width(column));
+        }
+
+        ResolvedLayout {
+            sublayouts: (),
+            resolved_size: size,
+        }
+    }
+}
+
+impl<'a, T: Slice, F: CharacterFont<Color>, Color: Copy> CharacterRender<Color>
+    for VerticalText<'a, T, F>
+{
+    fn render(
+        &self,
+        target: &mut impl CharacterRenderTarget<Color = Color>,
+        layout: &ResolvedLayout<()>,
+        origin: Point,
+        env: &impl RenderEnvironment<Color = Color>,
+    ) {
+        if layout.resolved_size.area() == 0 {
+            return;
+        }
+
+        let font = self.text.font;
+        let line_height = font.line_height() as i16;
+        let metrics = VerticalMetrics(font);
+        let color = env.foreground_color();
+
+        let mut width = 0;
+        let wrap = WhitespaceWrap::new(
+            self.text.text.as_slice(),
+            layout.resolved_size.height,
+            &metrics,
+        );
+        for column in wrap {
+            let column_width = self.column_width(column) as i16;
+            let mut y = self.text.alignment.align(
+                layout.resolved_size.height as i16,
+                metrics.str_width(column) as i16,
+            );
+            for character in column.chars() {
+                let x = (column_width - font.character_width(character) as i16) / 2;
+                font.render_iter_solid(
+                    target,
+                    Point::new(origin.x + width + x, origin.y + y),
+                    color,
+                    core::iter::once(character),
+                );
+                y += line_height;
+            }
+
+            width += column_width;
+            if width >= layout.resolved_size.width as i16 {
+                break;
+            }
+        }
+    }
+}
+
+#[cfg(feature = "embedded-graphics")]
+impl<
+        'a,
+        T: Slice,
+        F: crate::font::PixelFont<Color>,
+        Color: embedded_graphics_core::pixelcolor::PixelColor,
+    > crate::render::PixelRender<Color> for VerticalText<'a, T, F>
+{
+    fn render(
+        &self,
+        target: &mut impl embedded_graphics::draw_target::DrawTarget<Color = Color>,
+        layout: &ResolvedLayout<()>,
+        origin: Point,
+        env: &impl RenderEnvironment<Color = Color>,
+    ) {
+        if layout.resolved_size.area() == 0 {
+            return;
+        }
+
+        let font = self.text.font;
+        let line_height = font.line_height() as i16;
+        let metrics = VerticalMetrics(font);
+        let color = env.foreground_color();
+
+        let mut width = 0;
+        let wrap = WhitespaceWrap::new(
+            self.text.text.as_slice(),
+            layout.resolved_size.height,
+            &metrics,
+        );
+        for column in wrap {
+            let column_width = self.column_width(column) as i16;
+            let mut y = self.text.alignment.align(
+                layout.resolved_size.height as i16,
+                metrics.str_width(column) as i16,
+            );
+            for character in column.chars() {
+                let x = (column_width - font.character_width(character) as i16) / 2;
+                font.render_iter(
+                    target,
+                    Point::new(origin.x + width + x, origin.y + y),
+                    color,
+                    core::iter::once(character),
+                );
+                y += line_height;
+            }
+
+            width += column_width;
+            if width >= layout.resolved_size.width as i16 {
+                break;
+            }
+        }
+    }
+}
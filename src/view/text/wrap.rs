@@ -1,27 +1,98 @@
 use crate::font::FontLayout;
 
+use super::tracking::Tracked;
+
+/// An iterator that breaks text into lines that fit within an available width.
+///
+/// This is the wrapping used by [`Text`](crate::view::Text), and can be used to build custom
+/// text views. Lines are broken at whitespace where possible, and words wider than the
+/// available width are split. Newlines always start a new line.
+///
+/// Each line is a slice borrowed from the input text. Widths are measured in the same units
+/// as [`FontLayout::character_width`].
+///
+/// ```
+/// use buoyant::font::{BufferCharacterFont, FontLayout};
+/// use buoyant::view::WhitespaceWrap;
+///
+/// let font = BufferCharacterFont;
+///
+/// // Lay out lines right-aligned to an 11 point wide column
+/// let origins = WhitespaceWrap::new("hello world, again", 11, &font)
+///     .enumerate()
+///     .map(|(i, line)| (11 - font.str_width(line), i as u16 * font.line_height(), line))
+///     .collect::<Vec<_>>();
+///
+/// assert_eq!(origins, vec![(6, 0, "hello"), (5, 1, "world,"), (6, 2, "again")]);
+/// ```
 pub struct WhitespaceWrap<'a, F> {
     remaining: &'a str,
     overflow: &'a str,
     available_width: u16,
     font: &'a F,
+    tab_width: u16,
 }
 
 impl<'a, F: FontLayout> WhitespaceWrap<'a, F> {
+    /// Creates an iterator over the lines of `text` when wrapped to `available_width`
     pub fn new(text: &'a str, available_width: u16, font: &'a F) -> Self {
         Self {
             remaining: text,
             overflow: &text[0..0],
             available_width,
             font,
+            tab_width: 0,
         }
     }
 
+    /// Expands tabs to the next multiple of `tab_width` spaces from the start of the line.
+    ///
+    /// A width of zero measures tabs with the font, like any other character.
+    pub fn tab_width(self, tab_width: u16) -> Self {
+        Self { tab_width, ..self }
+    }
+
+    /// Produces each line along with its width
+    ///
+    /// ```
+    /// use buoyant::font::BufferCharacterFont;
+    /// use buoyant::view::{WhitespaceWrap, WrappedLine};
+    ///
+    /// let lines = WhitespaceWrap::new("hello world", 10, &BufferCharacterFont)
+    ///     .measured()
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(
+    ///     lines.iter().map(|line| line.content).collect::<Vec<_>>(),
+    ///     vec!["hello", "world"]
+    /// );
+    /// assert_eq!(lines[0], WrappedLine { content: "hello", width: 5 });
+    /// ```
+    pub fn measured(self) -> impl Iterator<Item = WrappedLine<'a>> {
+        let metrics = self.metrics();
+        self.map(move |content| WrappedLine {
+            content,
+            width: content
+                .chars()
+                .fold(0, |x, character| x + metrics.advance(character, x)),
+        })
+    }
+
+    /// The font, with tabs expanded to tab stops
+    fn metrics(&self) -> Tracked<'a, F> {
+        Tracked::new(self.font, 0, self.tab_width)
+    }
+
+    /// The advance of a character that starts at `x` points from the start of the line
+    fn advance(&self, character: char, x: u16) -> u16 {
+        self.metrics().advance(character, x)
+    }
+
     // Helper function to find force split position
     fn find_split_pos(&self, text: &str) -> Option<usize> {
         let mut width = 0;
         for (pos, ch) in text.char_indices() {
-            width += self.font.character_width(ch);
+            width += self.advance(ch, width);
             if width > self.available_width {
                 return Some(if pos > 0 { pos } else { 1 });
             }
@@ -30,6 +101,15 @@ impl<'a, F: FontLayout> WhitespaceWrap<'a, F> {
     }
 }
 
+/// A line of wrapped text, along with its width
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WrappedLine<'a> {
+    /// The text of the line, borrowed from the wrapped text
+    pub content: &'a str,
+    /// The width of the line, in the same units as [`FontLayout::character_width`]
+    pub width: u16,
+}
+
 impl<'a, F: FontLayout> Iterator for WhitespaceWrap<'a, F> {
     type Item = &'a str;
 
@@ -78,7 +158,7 @@ impl<'a, F: FontLayout> Iterator for WhitespaceWrap<'a, F> {
                 return Some(line.trim_end());
             }
 
-            width += self.font.character_width(ch);
+            width += self.advance(ch, width);
 
             if ch.is_whitespace() {
                 last_space = Some(pos);
@@ -106,7 +186,7 @@ impl<'a, F: FontLayout> Iterator for WhitespaceWrap<'a, F> {
             let mut end = self.remaining.len();
             let mut width = 0;
             for (pos, ch) in self.remaining.char_indices() {
-                width += self.font.character_width(ch);
+                width += self.advance(ch, width);
                 if width > self.available_width {
                     end = pos;
                     break;
@@ -275,6 +355,44 @@ mod tests {
         }
     }
 
+    #[test]
+    fn tab_at_start_of_line_expands_to_tab_stop() {
+        let wrap = super::WhitespaceWrap::new("\tab", 6, &FONT).tab_width(4);
+        assert_eq!(wrap.collect::<Vec<_>>(), vec!["\tab"]);
+        let wrap = super::WhitespaceWrap::new("\tab", 5, &FONT).tab_width(4);
+        assert_eq!(wrap.collect::<Vec<_>>(), vec!["", "ab"]);
+    }
+
+    #[test]
+    fn tab_expansion_pushes_content_past_available_width() {
+        // Without expansion, the tab is a single unit and the text fits
+        let wrap = super::WhitespaceWrap::new("ab\tcd", 5, &FONT);
+        assert_eq!(wrap.collect::<Vec<_>>(), vec!["ab\tcd"]);
+        // The tab advances from 2 to 4, leaving "cd" at 4..6
+        let wrap = super::WhitespaceWrap::new("ab\tcd", 6, &FONT).tab_width(4);
+        assert_eq!(wrap.collect::<Vec<_>>(), vec!["ab\tcd"]);
+        let wrap = super::WhitespaceWrap::new("ab\tcd", 5, &FONT).tab_width(4);
+        assert_eq!(wrap.collect::<Vec<_>>(), vec!["ab", "cd"]);
+    }
+
+    #[test]
+    fn tab_stops_are_measured_in_spaces() {
+        // Whitespace is 2 units wide, so tab stops are every 4 units
+        let wrap = super::WhitespaceWrap::new("1\t1", 5, &VariableWidthFont).tab_width(2);
+        assert_eq!(wrap.collect::<Vec<_>>(), vec!["1\t1"]);
+        let wrap = super::WhitespaceWrap::new("1\t1", 4, &VariableWidthFont).tab_width(2);
+        assert_eq!(wrap.collect::<Vec<_>>(), vec!["1", "1"]);
+    }
+
+    #[test]
+    fn measured_lines_include_tab_expansion() {
+        let wrap = super::WhitespaceWrap::new("ab\tc\nd", 10, &FONT).tab_width(4);
+        assert_eq!(
+            wrap.measured().map(|line| line.width).collect::<Vec<_>>(),
+            vec![5, 1]
+        );
+    }
+
     #[test]
     fn variable_width_wrapping() {
         let wrap = super::WhitespaceWrap::new("1 2 3 4 5 6", 5, &VariableWidthFont);
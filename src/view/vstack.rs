@@ -2,16 +2,25 @@ use core::cmp::{max, min};
 
 use crate::{
     environment::{LayoutEnvironment, RenderEnvironment},
-    layout::{HorizontalAlignment, Layout, LayoutDirection, ResolvedLayout},
+    layout::{HorizontalAlignment, Layout, LayoutDirection, OverflowBehavior, ResolvedLayout},
     primitives::{Point, Size},
     render::CharacterRender,
     render_target::CharacterRenderTarget,
 };
 
+use super::modifier::ClippedTarget;
+
+/// Lays out views top to bottom, offering space to higher priority views first.
+///
+/// Before sizing each priority group, the stack holds back the minimum height of every lower
+/// priority view, measured by offering it zero height. Higher priority views give way to lower
+/// priority views which refuse to shrink, such as a spacer with a minimum length, while lower
+/// priority views that can shrink to nothing reserve no space.
 pub struct VStack<T> {
-    items: T,
-    alignment: HorizontalAlignment,
-    spacing: u16,
+    pub(crate) items: T,
+    pub(crate) alignment: HorizontalAlignment,
+    pub(crate) spacing: u16,
+    pub(crate) overflow: OverflowBehavior,
 }
 
 struct VerticalEnvironment<'a, T> {
@@ -26,6 +35,10 @@ impl<T: LayoutEnvironment> LayoutEnvironment for VerticalEnvironment<'_, T> {
     fn layout_direction(&self) -> LayoutDirection {
         LayoutDirection::Vertical
     }
+
+    fn value<V: 'static>(&self) -> Option<&V> {
+        self.inner_environment.value()
+    }
 }
 
 impl<Color: Copy, T: RenderEnvironment<Color = Color>> RenderEnvironment
@@ -47,7 +60,9 @@ impl<'a, T: LayoutEnvironment> From<&'a T> for VerticalEnvironment<'a, T> {
 
 impl<T> PartialEq for VStack<T> {
     fn eq(&self, other: &Self) -> bool {
-        self.spacing == other.spacing && self.alignment == other.alignment
+        self.spacing == other.spacing
+            && self.alignment == other.alignment
+            && self.overflow == other.overflow
     }
 }
 
@@ -57,6 +72,7 @@ impl<T> VStack<T> {
             items,
             alignment: HorizontalAlignment::default(),
             spacing: 0,
+            overflow: OverflowBehavior::default(),
         }
     }
 
@@ -67,6 +83,16 @@ impl<T> VStack<T> {
     pub fn with_alignment(self, alignment: HorizontalAlignment) -> Self {
         Self { alignment, ..self }
     }
+
+    /// Sets what happens to children that do not fit in the stack
+    pub fn with_overflow_behavior(self, overflow: OverflowBehavior) -> Self {
+        Self { overflow, ..self }
+    }
+
+    /// Whether children are clipped to the bounds of the stack along its axis
+    fn clips(&self) -> bool {
+        self.overflow == OverflowBehavior::ClipLast
+    }
 }
 
 impl<U: Layout, V: Layout> Layout for VStack<(U, V)> {
@@ -194,6 +220,15 @@ fn layout_n<const N: usize>(
             break;
         }
 
+        // Hold back the minimum height of lower priority views, so they are not squeezed out
+        let reserved = subviews
+            .iter_mut()
+            .filter(|(stage, _, priority)| *stage == LayoutStage::Unsized && *priority < max)
+            .map(|(_, layout, _)| layout(Size::new(offer.width, 0)).height)
+            .fold(0, u16::saturating_add)
+            .min(remaining_height);
+        remaining_height -= reserved;
+
         // Size all the unsized views that are unwilling to shrink
         let mut group_offer = Size::new(offer.width, remaining_height / slice_len as u16);
         let mut remainder = remaining_height as usize % slice_len;
@@ -264,6 +299,7 @@ fn layout_n<const N: usize>(
                 }
             }
         }
+        remaining_height += reserved;
     }
 
     // At this point all the subviews should have either a final or a candidate size
@@ -301,6 +337,8 @@ impl<Pixel: Copy, U: CharacterRender<Pixel>, V: CharacterRender<Pixel>> Characte
         origin: Point,
         env: &impl RenderEnvironment<Color = Pixel>,
     ) {
+        let target =
+            &mut ClippedTarget::new(target, origin, layout.resolved_size, false, self.clips());
         let env = &VerticalEnvironment::from(env);
 
         let mut height = 0;
@@ -347,6 +385,8 @@ where
         origin: Point,
         env: &impl RenderEnvironment<Color = Pixel>,
     ) {
+        let target =
+            &mut ClippedTarget::new(target, origin, layout.resolved_size, false, self.clips());
         let env = &VerticalEnvironment::from(env);
 
         let mut height = 0;
@@ -395,7 +435,10 @@ where
 // -- Embedded Render
 
 #[cfg(feature = "embedded-graphics")]
-use embedded_graphics::draw_target::DrawTarget;
+use embedded_graphics::draw_target::{DrawTarget, DrawTargetExt as _};
+
+#[cfg(feature = "embedded-graphics")]
+use super::modifier::clip_area;
 
 #[cfg(feature = "embedded-graphics")]
 impl<Pixel, U: crate::render::PixelRender<Pixel>, V: crate::render::PixelRender<Pixel>>
@@ -410,6 +453,12 @@ where
         origin: Point,
         env: &impl RenderEnvironment<Color = Pixel>,
     ) {
+        let target = &mut target.clipped(&clip_area(
+            origin,
+            layout.resolved_size,
+            false,
+            self.clips(),
+        ));
         let env = &VerticalEnvironment::from(env);
 
         let mut height = 0;
@@ -458,6 +507,12 @@ where
         origin: Point,
         env: &impl RenderEnvironment<Color = Pixel>,
     ) {
+        let target = &mut target.clipped(&clip_area(
+            origin,
+            layout.resolved_size,
+            false,
+            self.clips(),
+        ));
         let env = &VerticalEnvironment::from(env);
 
         let mut height = 0;
@@ -0,0 +1,66 @@
+use buoyant::{
+    environment::DefaultEnvironment,
+    font::{CachedMetrics, FontLayout},
+    layout::Layout as _,
+    primitives::Size,
+    view::Text,
+};
+use common::TracedFont;
+
+mod common;
+
+#[test]
+fn cached_font_is_queried_less_across_layouts() {
+    let env = DefaultEnvironment::new(());
+    let offer = Size::new(6, 10);
+    let content = "the quick brown fox";
+
+    let font = TracedFont::default();
+    let text = Text::str(content, &font);
+    let first = text.layout(offer, &env);
+    text.layout(offer, &env);
+    let uncached_queries = font.measurements.get();
+
+    let font = CachedMetrics::<_, 16>::new(TracedFont::default());
+    let text = Text::str(content, &font);
+    let cached = text.layout(offer, &env);
+    let first_pass_queries = font.font().measurements.get();
+    text.layout(offer, &env);
+
+    assert_eq!(first.resolved_size, cached.resolved_size);
+    // Each distinct character is measured once, and the second layout is free
+    assert_eq!(first_pass_queries, 16);
+    assert_eq!(font.font().measurements.get(), first_pass_queries);
+    assert!(uncached_queries > 2 * first_pass_queries);
+}
+
+#[test]
+fn repeated_characters_are_measured_once() {
+    let font = CachedMetrics::<_, 4>::new(TracedFont::default());
+    assert_eq!(font.str_width("abcabc"), 6);
+    assert_eq!(font.font().measurements.get(), 3);
+}
+
+#[test]
+fn least_recently_used_width_is_evicted() {
+    let font = CachedMetrics::<_, 2>::new(TracedFont::default());
+    font.character_width('a');
+    font.character_width('b');
+    // a becomes the most recently used, so c evicts b
+    font.character_width('a');
+    font.character_width('c');
+    assert_eq!(font.font().measurements.get(), 3);
+    font.character_width('a');
+    assert_eq!(font.font().measurements.get(), 3);
+    font.character_width('b');
+    assert_eq!(font.font().measurements.get(), 4);
+}
+
+#[test]
+fn clear_forgets_widths() {
+    let font = CachedMetrics::<_, 2>::new(TracedFont::default());
+    font.character_width('a');
+    font.clear();
+    font.character_width('a');
+    assert_eq!(font.font().measurements.get(), 2);
+}
@@ -0,0 +1,77 @@
+#![cfg(feature = "embedded-graphics")]
+
+use buoyant::{
+    environment::DefaultEnvironment,
+    primitives::{Point, Size},
+    render::PixelRender,
+    view::{Circle, LayoutExtensions as _, Rectangle},
+};
+use embedded_graphics::{mock_display::MockDisplay, pixelcolor::BinaryColor};
+
+fn render(view: &impl PixelRender<BinaryColor>, size: Size) -> MockDisplay<BinaryColor> {
+    let env = DefaultEnvironment::new(BinaryColor::On);
+    let mut display = MockDisplay::new();
+    let layout = view.layout(size, &env);
+    view.render(&mut display, &layout, Point::new(1, 1), &env);
+    display
+}
+
+fn is_on(display: &MockDisplay<BinaryColor>, x: i32, y: i32) -> bool {
+    display.get_pixel(embedded_graphics::geometry::Point::new(x, y)) == Some(BinaryColor::On)
+}
+
+#[test]
+fn circle_clip_matches_circle_fill() {
+    let clipped = render(&Rectangle.clip_shape(Circle), Size::new(8, 8));
+    let filled = render(&Circle, Size::new(8, 8));
+    clipped.assert_eq(&filled);
+    assert!(!is_on(&clipped, 1, 1));
+    assert!(!is_on(&clipped, 8, 8));
+    assert!(is_on(&clipped, 4, 4));
+}
+
+#[test]
+fn circle_clip_is_centered_in_wide_frames() {
+    let display = render(&Rectangle.clip_shape(Circle), Size::new(10, 4));
+    // The circle spans columns 3..7 of the frame
+    assert!(!is_on(&display, 3, 2));
+    assert!(is_on(&display, 4, 2));
+    assert!(is_on(&display, 7, 2));
+    assert!(!is_on(&display, 8, 2));
+}
+
+#[test]
+fn nested_clips_use_intersection() {
+    let view = Rectangle
+        .clip_shape(Circle)
+        .frame(Some(8), Some(8), None, None)
+        .frame(Some(4), Some(8), None, None)
+        .clipped();
+    let display = render(&view, Size::new(4, 8));
+    // The circle overflows the narrower frame on both sides, so only its middle columns remain
+    assert!(!is_on(&display, 0, 4));
+    assert!(is_on(&display, 1, 4));
+    assert!(is_on(&display, 4, 4));
+    assert!(!is_on(&display, 5, 4));
+}
+
+#[test]
+fn clip_corner_radius_clips_corners() {
+    let display = render(&Rectangle.clip_corner_radius(3), Size::new(10, 8));
+    for (x, y) in [(1, 1), (10, 1), (1, 8), (10, 8)] {
+        assert!(
+            !is_on(&display, x, y),
+            "corner ({x}, {y}) should be clipped"
+        );
+    }
+    for (x, y) in [(4, 1), (1, 4), (10, 4), (5, 8), (5, 4)] {
+        assert!(is_on(&display, x, y), "({x}, {y}) should be drawn");
+    }
+}
+
+#[test]
+fn clip_corner_radius_matches_rounded_rectangle_fill() {
+    let clipped = render(&Rectangle.clip_corner_radius(3), Size::new(10, 8));
+    let filled = render(&Rectangle.corner_radius(3), Size::new(10, 8));
+    clipped.assert_eq(&filled);
+}
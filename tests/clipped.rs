@@ -0,0 +1,77 @@
+use buoyant::{
+    environment::DefaultEnvironment,
+    font::BufferCharacterFont,
+    primitives::{Point, Size},
+    render::CharacterRender,
+    render_target::FixedTextBuffer,
+    view::{LayoutExtensions as _, Text},
+};
+
+static FONT: BufferCharacterFont = BufferCharacterFont {};
+
+/// A 5x3 block of text centered in a 3x1 frame, overflowing it on every side
+fn overflowing() -> impl CharacterRender<()> {
+    Text::str("abcde\nfghij\nklmno", &FONT)
+        .frame(Some(5), Some(3), None, None)
+        .frame(Some(3), Some(1), None, None)
+}
+
+fn render(view: &impl CharacterRender<()>) -> Vec<String> {
+    let env = DefaultEnvironment::new(());
+    let mut buffer = FixedTextBuffer::<5, 3>::default();
+    let layout = view.layout(Size::new(3, 1), &env);
+    assert_eq!(layout.resolved_size, Size::new(3, 1));
+    view.render(&mut buffer, &layout, Point::new(1, 1), &env);
+    buffer
+        .text
+        .iter()
+        .map(|line| line.iter().collect())
+        .collect()
+}
+
+#[test]
+fn unclipped_content_overflows() {
+    assert_eq!(render(&overflowing()), ["abcde", "fghij", "klmno"]);
+}
+
+#[test]
+fn clipped_content_stays_in_bounds() {
+    assert_eq!(
+        render(&overflowing().clipped()),
+        ["     ", " ghi ", "     "]
+    );
+}
+
+#[test]
+fn horizontal_clip_allows_vertical_overflow() {
+    assert_eq!(
+        render(&overflowing().clipped_axis(true, false)),
+        [" bcd ", " ghi ", " lmn "]
+    );
+}
+
+#[test]
+fn vertical_clip_allows_horizontal_overflow() {
+    assert_eq!(
+        render(&overflowing().clipped_axis(false, true)),
+        ["     ", "fghij", "     "]
+    );
+}
+
+#[cfg(feature = "embedded-graphics")]
+#[test]
+fn pixel_clip_allows_vertical_overflow() {
+    use buoyant::{layout::Layout as _, render::PixelRender, view::Rectangle};
+    use embedded_graphics::{mock_display::MockDisplay, pixelcolor::BinaryColor};
+
+    let view = Rectangle
+        .frame(Some(5), Some(5), None, None)
+        .frame(Some(3), Some(3), None, None)
+        .clipped_axis(true, false);
+    let env = DefaultEnvironment::new(BinaryColor::On);
+    let mut display = MockDisplay::new();
+    let layout = view.layout(Size::new(3, 3), &env);
+    PixelRender::render(&view, &mut display, &layout, Point::new(1, 1), &env);
+
+    display.assert_pattern(&[" ### ", " ### ", " ### ", " ### ", " ### "]);
+}
@@ -1,5 +1,10 @@
+#![allow(dead_code)]
+
+use std::cell::Cell;
+
 use buoyant::{
     environment::{LayoutEnvironment, RenderEnvironment},
+    font::FontLayout,
     layout::{Alignment, LayoutDirection},
 };
 
@@ -57,3 +62,54 @@ impl<C> TestEnv<C> {
         self
     }
 }
+
+/// A fixed size character buffer that also records the color of each character
+pub struct ColorBuffer<C, const W: usize, const H: usize> {
+    pub text: [[char; W]; H],
+    pub colors: [[Option<C>; W]; H],
+}
+
+impl<C: Copy, const W: usize, const H: usize> Default for ColorBuffer<C, W, H> {
+    fn default() -> Self {
+        Self {
+            text: [[' '; W]; H],
+            colors: [[None; W]; H],
+        }
+    }
+}
+
+impl<C: Copy, const W: usize, const H: usize> buoyant::render_target::CharacterRenderTarget
+    for ColorBuffer<C, W, H>
+{
+    type Color = C;
+
+    fn size(&self) -> buoyant::primitives::Size {
+        buoyant::primitives::Size::new(W as u16, H as u16)
+    }
+
+    fn draw(&mut self, point: buoyant::primitives::Point, character: char, color: C) {
+        let x = point.x as usize;
+        let y = point.y as usize;
+        if point.x >= 0 && point.y >= 0 && y < H && x < W {
+            self.text[y][x] = character;
+            self.colors[y][x] = Some(color);
+        }
+    }
+}
+
+/// A font of single width characters which counts how many times characters are measured
+#[derive(Default)]
+pub struct TracedFont {
+    pub measurements: Cell<usize>,
+}
+
+impl FontLayout for TracedFont {
+    fn line_height(&self) -> u16 {
+        1
+    }
+
+    fn character_width(&self, _: char) -> u16 {
+        self.measurements.set(self.measurements.get() + 1);
+        1
+    }
+}
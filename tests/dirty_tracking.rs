@@ -0,0 +1,66 @@
+use buoyant::{
+    environment::DefaultEnvironment,
+    font::BufferCharacterFont,
+    layout::Layout as _,
+    primitives::{Point, Size},
+    render::CharacterRender,
+    render_target::{DirtyTracking, FixedTextBuffer},
+    view::Text,
+};
+
+#[test]
+fn nothing_drawn_is_clean() {
+    let target = DirtyTracking::new(FixedTextBuffer::<4, 4>::default());
+    assert_eq!(target.dirty_region(), None);
+}
+
+#[test]
+fn dirty_region_covers_drawn_text() {
+    let env = DefaultEnvironment::new(());
+    let mut target = DirtyTracking::new(FixedTextBuffer::<6, 4>::default());
+    let text = Text::str("ab\nc", &BufferCharacterFont);
+    let layout = text.layout(Size::new(6, 4), &env);
+    text.render(&mut target, &layout, Point::new(1, 2), &env);
+    assert_eq!(
+        target.dirty_region(),
+        Some((Point::new(1, 2), Size::new(2, 2)))
+    );
+
+    target.reset();
+    assert_eq!(target.dirty_region(), None);
+}
+
+#[test]
+fn points_outside_the_target_are_not_dirty() {
+    let env = DefaultEnvironment::new(());
+    let mut target = DirtyTracking::new(FixedTextBuffer::<4, 2>::default());
+    let text = Text::str("abcdef", &BufferCharacterFont);
+    let layout = text.layout(Size::new(6, 1), &env);
+    text.render(&mut target, &layout, Point::new(-1, 1), &env);
+    assert_eq!(
+        target.dirty_region(),
+        Some((Point::new(0, 1), Size::new(4, 1)))
+    );
+}
+
+#[cfg(feature = "embedded-graphics")]
+#[test]
+fn dirty_region_is_union_of_moving_rectangle() {
+    use buoyant::{
+        render::PixelRender,
+        view::{LayoutExtensions as _, Rectangle},
+    };
+    use embedded_graphics::{mock_display::MockDisplay, pixelcolor::BinaryColor};
+
+    let env = DefaultEnvironment::new(BinaryColor::On);
+    let mut target = DirtyTracking::new(MockDisplay::<BinaryColor>::new());
+    let view = Rectangle.frame(Some(2), Some(2), None, None);
+    let layout = view.layout(Size::new(2, 2), &env);
+    for origin in [Point::new(1, 1), Point::new(4, 2)] {
+        PixelRender::render(&view, &mut target, &layout, origin, &env);
+    }
+    assert_eq!(
+        target.dirty_region(),
+        Some((Point::new(1, 1), Size::new(5, 3)))
+    );
+}
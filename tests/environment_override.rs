@@ -0,0 +1,29 @@
+use buoyant::{
+    environment::DefaultEnvironment,
+    layout::{Layout as _, LayoutDirection},
+    primitives::{Point, Size},
+    render::CharacterRender,
+    render_target::{CharacterRenderTarget, FixedTextBuffer},
+    view::{Divider, LayoutExtensions as _},
+};
+
+#[test]
+fn overridden_direction_applies_to_subtree() {
+    let env = DefaultEnvironment::new(());
+    let view = Divider::default()
+        .environment_override(|values| values.layout_direction = LayoutDirection::Vertical);
+    let mut buffer = FixedTextBuffer::<3, 2>::default();
+    let layout = view.layout(buffer.size(), &env);
+    assert_eq!(layout.resolved_size, Size::new(3, 1));
+    view.render(&mut buffer, &layout, Point::zero(), &env);
+    assert_eq!(buffer.text[0].iter().collect::<String>(), "---");
+    assert_eq!(buffer.text[1].iter().collect::<String>(), "   ");
+}
+
+#[test]
+fn unmodified_values_are_inherited() {
+    let env = DefaultEnvironment::new(());
+    let view = Divider::default().environment_override(|_| {});
+    let layout = view.layout(Size::new(3, 2), &env);
+    assert_eq!(layout.resolved_size, Size::new(1, 2));
+}
@@ -0,0 +1,76 @@
+use buoyant::{
+    environment::{DefaultEnvironment, LayoutEnvironment, RenderEnvironment},
+    layout::{Layout, ResolvedLayout},
+    primitives::{Point, Size},
+    render::CharacterRender,
+    render_target::{CharacterRenderTarget, FixedTextBuffer},
+    view::{HStack, LayoutExtensions as _, VStack},
+};
+
+#[derive(Debug, PartialEq)]
+struct AccentColor(char);
+
+#[derive(Debug, PartialEq)]
+struct Width(u16);
+
+/// Draws its accent color, sized by the width in the environment
+struct Accent;
+
+impl Layout for Accent {
+    type Sublayout = ();
+
+    fn layout(&self, _: Size, env: &impl LayoutEnvironment) -> ResolvedLayout<()> {
+        let width = env.value::<Width>().map_or(1, |w| w.0);
+        ResolvedLayout {
+            sublayouts: (),
+            resolved_size: Size::new(width, 1),
+        }
+    }
+}
+
+impl<C: Copy> CharacterRender<C> for Accent {
+    fn render(
+        &self,
+        target: &mut impl CharacterRenderTarget<Color = C>,
+        layout: &ResolvedLayout<()>,
+        origin: Point,
+        env: &impl RenderEnvironment<Color = C>,
+    ) {
+        let accent = env.value::<AccentColor>().map_or('?', |a| a.0);
+        for x in 0..layout.resolved_size.width as i16 {
+            target.draw(origin + Point::new(x, 0), accent, env.foreground_color());
+        }
+    }
+}
+
+fn render<const W: usize, const H: usize>(view: &impl CharacterRender<()>) -> Vec<String> {
+    let env = DefaultEnvironment::new(());
+    let mut buffer = FixedTextBuffer::<W, H>::default();
+    let layout = view.layout(buffer.size(), &env);
+    view.render(&mut buffer, &layout, Point::zero(), &env);
+    buffer.text.iter().map(|row| row.iter().collect()).collect()
+}
+
+#[test]
+fn missing_values_are_none() {
+    assert_eq!(render::<1, 1>(&Accent), ["?"]);
+}
+
+#[test]
+fn nested_view_reads_injected_value() {
+    let view = VStack::new((HStack::new((Accent, Accent)), Accent)).environment(AccentColor('a'));
+    assert_eq!(render::<2, 2>(&view), ["aa", "a "]);
+}
+
+#[test]
+fn values_are_keyed_by_type() {
+    let view = Accent.environment(Width(3)).environment(AccentColor('a'));
+    assert_eq!(render::<4, 1>(&view), ["aaa "]);
+}
+
+#[test]
+fn innermost_value_wins() {
+    let view =
+        HStack::new((Accent.environment(AccentColor('i')), Accent)).environment(AccentColor('o'));
+    assert_eq!(render::<2, 1>(&view), ["io"]);
+}
@@ -228,3 +228,32 @@ fn test_render_frame_bottom_trailing_alignment() {
     assert_eq!(buffer.text[3].iter().collect::<String>(), "    bb");
     assert_eq!(buffer.text[4].iter().collect::<String>(), "    cc");
 }
+
+#[test]
+fn test_fractional_width() {
+    let font = BufferCharacterFont {};
+    let content = Text::str("123456", &font).frame_fraction(Some(0.5), None);
+    let env = DefaultEnvironment::new(());
+
+    assert_eq!(
+        content.layout(Size::new(100, 10), &env).resolved_size,
+        Size::new(50, 1)
+    );
+    // The child wraps to the fractional width
+    assert_eq!(
+        content.layout(Size::new(4, 10), &env).resolved_size,
+        Size::new(2, 3)
+    );
+}
+
+#[test]
+fn test_fractional_frame_renders_child_centered() {
+    let font = BufferCharacterFont {};
+    let content = Text::str("ab", &font).frame_fraction(Some(0.5), Some(1.0));
+    let env = DefaultEnvironment::new(());
+    let mut buffer = FixedTextBuffer::<8, 3>::default();
+    let layout = content.layout(buffer.size(), &env);
+    assert_eq!(layout.resolved_size, Size::new(4, 3));
+    content.render(&mut buffer, &layout, Point::zero(), &env);
+    assert_eq!(buffer.text[1].iter().collect::<String>(), " ab     ");
+}
@@ -350,3 +350,52 @@ fn test_render_oversize_mix() {
     assert_eq!(buffer.text[3].iter().collect::<String>(), "   bb ");
     assert_eq!(buffer.text[4].iter().collect::<String>(), "   cc ");
 }
+
+#[test]
+fn test_flex_width_passes_height_through() {
+    let font = BufferCharacterFont {};
+    let text = Text::str("123456", &font);
+    let content = text.flex_width(Some(2), Some(4), None);
+    let env = DefaultEnvironment::new(());
+
+    for offer in [
+        Size::new(1, 1),
+        Size::new(1, 123),
+        Size::new(100, 1),
+        Size::new(3, 6),
+    ] {
+        let layout = content.layout(offer, &env);
+        let child_offer = Size::new(offer.width.clamp(2, 4), offer.height);
+        let child = Text::str("123456", &font).layout(child_offer, &env);
+        assert_eq!(layout.resolved_size.height, child.resolved_size.height);
+    }
+
+    assert_eq!(
+        content.layout(Size::new(1, 123), &env).resolved_size,
+        Size::new(2, 3)
+    );
+    assert_eq!(
+        content.layout(Size::new(100, 1), &env).resolved_size,
+        Size::new(4, 1)
+    );
+}
+
+#[test]
+fn test_flex_height_passes_width_through() {
+    let font = BufferCharacterFont {};
+    let content = Text::str("123456", &font).flex_height(None, Some(2), None);
+    let env = DefaultEnvironment::new(());
+
+    assert_eq!(
+        content.layout(Size::new(1, 123), &env).resolved_size,
+        Size::new(1, 2)
+    );
+    assert_eq!(
+        content.layout(Size::new(100, 1), &env).resolved_size,
+        Size::new(6, 1)
+    );
+    assert_eq!(
+        content.layout(Size::new(4, 6), &env).resolved_size,
+        Size::new(4, 2)
+    );
+}
@@ -2,10 +2,10 @@ use buoyant::{
     environment::DefaultEnvironment,
     font::BufferCharacterFont,
     layout::{HorizontalAlignment, Layout as _, VerticalAlignment},
-    primitives::Point,
+    primitives::{Point, Size},
     render::CharacterRender,
     render_target::{CharacterRenderTarget, FixedTextBuffer},
-    view::{ForEach, HStack, Spacer, Text},
+    view::{Divider, ForEach, HStack, Spacer, Text},
 };
 
 static FONT: BufferCharacterFont = BufferCharacterFont {};
@@ -143,3 +143,168 @@ fn foreach_trailing_aligned() {
     assert_eq!(buffer.text[3].iter().collect::<String>(), "Name   77 ");
     assert_eq!(buffer.text[4].iter().collect::<String>(), "          ");
 }
+
+#[test]
+fn empty_foreach_renders_placeholder() {
+    let users = heapless::Vec::<User, 10>::new();
+
+    let view = ForEach::<10, _, _, _>::new(&users, |user| Text::str(&user.name, &FONT))
+        .empty(Text::str("No users", &FONT));
+    let env = DefaultEnvironment::new(());
+    let mut buffer = FixedTextBuffer::<10, 2>::default();
+    let layout = view.layout(buffer.size(), &env);
+    view.render(&mut buffer, &layout, Point::zero(), &env);
+    assert_eq!(buffer.text[0].iter().collect::<String>(), "No users  ");
+    assert_eq!(buffer.text[1].iter().collect::<String>(), "          ");
+}
+
+#[test]
+fn nonempty_foreach_ignores_placeholder() {
+    let mut users = heapless::Vec::<User, 10>::new();
+    users
+        .push(User {
+            name: "Alice".to_string(),
+            age: "99".to_string(),
+        })
+        .unwrap();
+
+    let view = ForEach::<10, _, _, _>::new(&users, |user| Text::str(&user.name, &FONT))
+        .with_alignment(HorizontalAlignment::Leading)
+        .empty(Text::str("No users", &FONT));
+    let env = DefaultEnvironment::new(());
+    let mut buffer = FixedTextBuffer::<10, 2>::default();
+    let layout = view.layout(buffer.size(), &env);
+    view.render(&mut buffer, &layout, Point::zero(), &env);
+    assert_eq!(buffer.text[0].iter().collect::<String>(), "Alice     ");
+    assert_eq!(buffer.text[1].iter().collect::<String>(), "          ");
+}
+
+#[test]
+fn horizontal_foreach_with_spacing() {
+    let letters = ["a", "b", "c"];
+    let view = ForEach::<3, _, _, _>::horizontal(&letters, |letter| Text::str(letter, &FONT))
+        .with_spacing(1);
+    let env = DefaultEnvironment::new(());
+    let mut buffer = FixedTextBuffer::<7, 1>::default();
+    let layout = view.layout(buffer.size(), &env);
+    assert_eq!(layout.resolved_size, Size::new(5, 1));
+    view.render(&mut buffer, &layout, Point::zero(), &env);
+    assert_eq!(buffer.text[0].iter().collect::<String>(), "a b c  ");
+}
+
+#[test]
+fn horizontal_foreach_aligns_on_cross_axis() {
+    let words = ["a", "b c", "d"];
+    let view = ForEach::<3, _, _, _>::horizontal(&words, |word| Text::str(word, &FONT))
+        .with_vertical_alignment(VerticalAlignment::Bottom)
+        .with_spacing(1);
+    let env = DefaultEnvironment::new(());
+    let mut buffer = FixedTextBuffer::<5, 2>::default();
+    let layout = view.layout(buffer.size(), &env);
+    assert_eq!(layout.resolved_size, Size::new(5, 2));
+    view.render(&mut buffer, &layout, Point::zero(), &env);
+    assert_eq!(buffer.text[0].iter().collect::<String>(), "  b  ");
+    assert_eq!(buffer.text[1].iter().collect::<String>(), "a c d");
+}
+
+#[test]
+fn vertical_foreach_with_spacing() {
+    let letters = ["a", "b", "c"];
+    let view =
+        ForEach::<3, _, _, _>::new(&letters, |letter| Text::str(letter, &FONT)).with_spacing(1);
+    let env = DefaultEnvironment::new(());
+    let mut buffer = FixedTextBuffer::<1, 6>::default();
+    let layout = view.layout(buffer.size(), &env);
+    assert_eq!(layout.resolved_size, Size::new(1, 5));
+    view.render(&mut buffer, &layout, Point::zero(), &env);
+    let column = buffer.text.iter().map(|row| row[0]).collect::<String>();
+    assert_eq!(column, "a b c ");
+}
+
+#[test]
+fn separators_are_placed_between_items() {
+    let letters = ["a", "b", "c"];
+    let view = ForEach::<3, _, _, _>::new(&letters, |letter| Text::str(letter, &FONT))
+        .separated(Divider::default());
+    let env = DefaultEnvironment::new(());
+    let mut buffer = FixedTextBuffer::<1, 6>::default();
+    let layout = view.layout(buffer.size(), &env);
+    assert_eq!(layout.resolved_size, Size::new(1, 5));
+    view.render(&mut buffer, &layout, Point::zero(), &env);
+    let column = buffer.text.iter().map(|row| row[0]).collect::<String>();
+    assert_eq!(column, "a-b-c ");
+    assert_eq!(column.matches('-').count(), letters.len() - 1);
+}
+
+#[test]
+fn separators_are_surrounded_by_spacing() {
+    let letters = ["a", "b", "c"];
+    let view = ForEach::<3, _, _, _>::horizontal(&letters, |letter| Text::str(letter, &FONT))
+        .with_spacing(1)
+        .separated(Divider::default());
+    let env = DefaultEnvironment::new(());
+    let mut buffer = FixedTextBuffer::<10, 1>::default();
+    let layout = view.layout(buffer.size(), &env);
+    assert_eq!(layout.resolved_size, Size::new(9, 1));
+    view.render(&mut buffer, &layout, Point::zero(), &env);
+    assert_eq!(buffer.text[0].iter().collect::<String>(), "a | b | c ");
+}
+
+#[test]
+fn single_item_has_no_separator() {
+    let letters = ["a"];
+    let view = ForEach::<3, _, _, _>::new(&letters, |letter| Text::str(letter, &FONT))
+        .separated(Divider::default());
+    let env = DefaultEnvironment::new(());
+    let mut buffer = FixedTextBuffer::<1, 2>::default();
+    let layout = view.layout(buffer.size(), &env);
+    assert_eq!(layout.resolved_size, Size::new(1, 1));
+    view.render(&mut buffer, &layout, Point::zero(), &env);
+    assert_eq!(
+        buffer.text.iter().map(|row| row[0]).collect::<String>(),
+        "a "
+    );
+}
+
+#[test]
+fn empty_collection_has_no_separator() {
+    let letters: [&str; 0] = [];
+    let view = ForEach::<3, _, _, _>::new(&letters, |letter| Text::str(letter, &FONT))
+        .with_spacing(1)
+        .separated(Divider::default());
+    let env = DefaultEnvironment::new(());
+    let mut buffer = FixedTextBuffer::<1, 3>::default();
+    let layout = view.layout(buffer.size(), &env);
+    assert_eq!(layout.resolved_size.height, 0);
+    view.render(&mut buffer, &layout, Point::zero(), &env);
+    assert_eq!(
+        buffer.text.iter().map(|row| row[0]).collect::<String>(),
+        "   "
+    );
+}
+
+#[test]
+fn empty_separated_collection_shows_placeholder() {
+    let letters: [&str; 0] = [];
+    let view = ForEach::<3, _, _, _>::new(&letters, |letter| Text::str(letter, &FONT))
+        .separated(Divider::default())
+        .empty(Text::str("none", &FONT));
+    let env = DefaultEnvironment::new(());
+    let mut buffer = FixedTextBuffer::<4, 2>::default();
+    let layout = view.layout(buffer.size(), &env);
+    assert_eq!(layout.resolved_size, Size::new(4, 1));
+    view.render(&mut buffer, &layout, Point::zero(), &env);
+    assert_eq!(buffer.text[0].iter().collect::<String>(), "none");
+    assert_eq!(buffer.text[1].iter().collect::<String>(), "    ");
+}
+
+#[test]
+fn large_separator_spacing_saturates() {
+    let letters = ["a", "b", "c"];
+    let view = ForEach::<3, _, _, _>::new(&letters, |letter| Text::str(letter, &FONT))
+        .with_spacing(u16::MAX)
+        .separated(Divider::default());
+    let env = DefaultEnvironment::new(());
+    let layout = view.layout(Size::new(1, 4), &env);
+    assert!(layout.resolved_size.height <= 4);
+}
@@ -0,0 +1,87 @@
+use buoyant::{
+    environment::{DefaultEnvironment, LayoutEnvironment, RenderEnvironment},
+    layout::{Layout, ResolvedLayout},
+    primitives::{Point, Size},
+    render::CharacterRender,
+    render_target::{CharacterRenderTarget, FixedTextBuffer},
+    view::{
+        CharacterRenderExtensions as _, Divider, HStack, LayoutExtensions as _, Rectangle, VStack,
+    },
+};
+use common::ColorBuffer;
+
+mod common;
+
+/// A custom view that draws a single character in the inherited foreground color
+struct Swatch;
+
+impl Layout for Swatch {
+    type Sublayout = ();
+
+    fn layout(&self, _: Size, _: &impl LayoutEnvironment) -> ResolvedLayout<()> {
+        ResolvedLayout {
+            sublayouts: (),
+            resolved_size: Size::new(1, 1),
+        }
+    }
+}
+
+impl<C: Copy> CharacterRender<C> for Swatch {
+    fn render(
+        &self,
+        target: &mut impl CharacterRenderTarget<Color = C>,
+        _: &ResolvedLayout<()>,
+        origin: Point,
+        env: &impl RenderEnvironment<Color = C>,
+    ) {
+        target.draw(origin, '#', env.foreground_color());
+    }
+}
+
+#[test]
+fn custom_view_reads_inherited_foreground_color() {
+    let view =
+        HStack::new((Swatch, Rectangle.frame(Some(1), Some(1), None, None))).foreground_color('r');
+    let env = DefaultEnvironment::new('d');
+    let mut buffer = ColorBuffer::<char, 2, 1>::default();
+    let layout = view.layout(buffer.size(), &env);
+    view.render(&mut buffer, &layout, Point::zero(), &env);
+
+    assert_eq!(buffer.text[0][0], '#');
+    assert_eq!(buffer.colors[0][0], Some('r'));
+    assert_eq!(buffer.colors[0][0], buffer.colors[0][1]);
+}
+
+#[test]
+fn innermost_foreground_color_wins() {
+    let view = Swatch.foreground_color('i').foreground_color('o');
+    let env = DefaultEnvironment::new('d');
+    let mut buffer = ColorBuffer::<char, 1, 1>::default();
+    let layout = view.layout(buffer.size(), &env);
+    view.render(&mut buffer, &layout, Point::zero(), &env);
+
+    assert_eq!(buffer.colors[0][0], Some('i'));
+}
+
+#[test]
+fn default_environment_color_is_used_without_modifier() {
+    let env = DefaultEnvironment::new(());
+    let mut buffer = FixedTextBuffer::<1, 1>::default();
+    let layout = Swatch.layout(buffer.size(), &env);
+    Swatch.render(&mut buffer, &layout, Point::zero(), &env);
+
+    assert_eq!(buffer.text[0][0], '#');
+}
+
+#[test]
+fn divider_color_overrides_foreground() {
+    let view = VStack::new((Divider::default().color('r'), Swatch));
+    let env = DefaultEnvironment::new('d');
+    let mut buffer = ColorBuffer::<char, 3, 2>::default();
+    let layout = view.layout(buffer.size(), &env);
+    view.render(&mut buffer, &layout, Point::zero(), &env);
+
+    assert_eq!(buffer.text[0], ['-'; 3]);
+    assert_eq!(buffer.colors[0], [Some('r'); 3]);
+    assert_eq!(buffer.colors[1][1], Some('d'));
+}
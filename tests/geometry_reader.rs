@@ -0,0 +1,40 @@
+use buoyant::{
+    environment::DefaultEnvironment,
+    font::BufferCharacterFont,
+    primitives::{Point, Size},
+    render::CharacterRender,
+    render_target::{CharacterRenderTarget, FixedTextBuffer},
+    view::{ConditionalView, GeometryReader, HStack, Text, VStack},
+};
+
+static FONT: BufferCharacterFont = BufferCharacterFont {};
+
+/// Shows two columns side by side when there is room, otherwise stacks them
+fn adaptive() -> impl CharacterRender<()> {
+    GeometryReader::new(|size: Size| {
+        ConditionalView::new(
+            size.width >= 6,
+            HStack::new((Text::str("abc", &FONT), Text::str("def", &FONT))),
+            VStack::new((Text::str("abc", &FONT), Text::str("def", &FONT))),
+        )
+    })
+}
+
+fn render<const W: usize, const H: usize>(view: &impl CharacterRender<()>) -> Vec<String> {
+    let env = DefaultEnvironment::new(());
+    let mut buffer = FixedTextBuffer::<W, H>::default();
+    let layout = view.layout(buffer.size(), &env);
+    assert_eq!(layout.resolved_size, buffer.size());
+    view.render(&mut buffer, &layout, Point::zero(), &env);
+    buffer.text.iter().map(|row| row.iter().collect()).collect()
+}
+
+#[test]
+fn wide_offer_uses_two_columns() {
+    assert_eq!(render::<7, 2>(&adaptive()), ["abcdef ", "       "]);
+}
+
+#[test]
+fn narrow_offer_uses_one_column() {
+    assert_eq!(render::<4, 2>(&adaptive()), ["abc ", "def "]);
+}
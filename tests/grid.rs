@@ -0,0 +1,81 @@
+use buoyant::{
+    environment::DefaultEnvironment,
+    font::BufferCharacterFont,
+    layout::Layout as _,
+    primitives::{Point, Size},
+    render::CharacterRender,
+    render_target::{CharacterRenderTarget, FixedTextBuffer},
+    view::{Grid, GridLayout, LayoutExtensions as _, Rectangle, Text, TrackSize},
+};
+
+static FONT: BufferCharacterFont = BufferCharacterFont {};
+
+#[test]
+fn flexible_column_takes_leftover_width() {
+    let items = [1, 2, 3, 4, 5, 6];
+    let grid = Grid::<6, 3, _, _, _>::new(
+        [
+            TrackSize::Fixed(2),
+            TrackSize::Flexible(1),
+            TrackSize::Fixed(3),
+        ],
+        &items,
+        |_| Rectangle.frame(None, Some(1), None, None),
+    );
+    let env = DefaultEnvironment::new(());
+    let layout = grid.layout(Size::new(10, 10), &env);
+
+    assert_eq!(layout.resolved_size, Size::new(10, 2));
+    let grid_layout: &GridLayout<_, 6, 3> = &layout.sublayouts;
+    assert_eq!(grid_layout.column_widths(), &[2, 5, 3]);
+    let widths = grid_layout
+        .cells()
+        .iter()
+        .map(|cell| cell.resolved_size.width)
+        .collect::<Vec<_>>();
+    assert_eq!(widths, [2, 5, 3, 2, 5, 3]);
+}
+
+#[test]
+fn flexible_columns_split_by_weight() {
+    let items = [0; 3];
+    let grid = Grid::<3, 3, _, _, _>::new(
+        [
+            TrackSize::Flexible(1),
+            TrackSize::Flexible(2),
+            TrackSize::Fixed(1),
+        ],
+        &items,
+        |_| Rectangle,
+    )
+    .with_spacing(1);
+    let env = DefaultEnvironment::new(());
+    let layout = grid.layout(Size::new(12, 1), &env);
+    assert_eq!(layout.sublayouts.column_widths(), &[3, 6, 1]);
+
+    // The remainder goes to the leading flexible column
+    let layout = grid.layout(Size::new(13, 1), &env);
+    assert_eq!(layout.sublayouts.column_widths(), &[4, 6, 1]);
+}
+
+#[test]
+fn rows_wrap_and_size_to_tallest_cell() {
+    let items = ["a", "bb", "c d", "e f", "g"];
+    let grid = Grid::<5, 2, _, _, _>::new([TrackSize::Auto, TrackSize::Fixed(2)], &items, |text| {
+        Text::str(text, &FONT)
+    });
+    let env = DefaultEnvironment::new(());
+    let mut buffer = FixedTextBuffer::<6, 5>::default();
+    let layout = grid.layout(buffer.size(), &env);
+    assert_eq!(layout.sublayouts.column_widths(), &[3, 2]);
+    // "e f" wraps to two lines in its fixed column, making the second row two lines tall
+    assert_eq!(layout.sublayouts.cells()[3].resolved_size, Size::new(1, 2));
+    assert_eq!(layout.resolved_size, Size::new(5, 4));
+
+    grid.render(&mut buffer, &layout, Point::zero(), &env);
+    // The third row starts below both lines of the second row
+    let lines = ["a  bb ", "c de  ", "   f  ", "g     ", "      "];
+    for (expected, actual) in lines.iter().zip(buffer.text.iter()) {
+        assert_eq!(actual.iter().collect::<String>(), *expected);
+    }
+}
@@ -1,8 +1,8 @@
 use std::iter::zip;
 
 use buoyant::environment::DefaultEnvironment;
-use buoyant::font::BufferCharacterFont;
-use buoyant::layout::{Layout, VerticalAlignment};
+use buoyant::font::{BufferCharacterFont, CharacterFont, FontLayout};
+use buoyant::layout::{Layout, OverflowBehavior, VerticalAlignment};
 use buoyant::primitives::{Point, Size};
 use buoyant::render::CharacterRender;
 use buoyant::render_target::{CharacterRenderTarget, FixedTextBuffer};
@@ -331,3 +331,175 @@ fn test_layout_3_extra_space_allocation() {
     assert_eq!(buffer.text[1].iter().collect::<String>(), "xxxxT++++");
     assert_eq!(buffer.text[2].iter().collect::<String>(), "xxxx ++++");
 }
+
+/// A font taller than its glyphs, which are drawn on the row above the baseline
+struct TallFont;
+
+impl FontLayout for TallFont {
+    fn line_height(&self) -> u16 {
+        3
+    }
+
+    fn character_width(&self, _: char) -> u16 {
+        1
+    }
+
+    fn baseline(&self) -> u16 {
+        2
+    }
+}
+
+impl CharacterFont<()> for TallFont {
+    fn render_iter<T, I>(&self, target: &mut T, origin: Point, characters: I)
+    where
+        T: CharacterRenderTarget<Color = ()>,
+        I: IntoIterator<Item = (char, ())>,
+    {
+        for (i, (character, color)) in characters.into_iter().enumerate() {
+            target.draw(origin + Point::new(i as i16, 1), character, color);
+        }
+    }
+}
+
+fn render_baselines(alignment: VerticalAlignment) -> (Size, Vec<String>) {
+    let hstack = HStack::new((
+        Text::str("X", &TallFont),
+        Text::str("a\nb", &BufferCharacterFont),
+    ))
+    .with_alignment(alignment);
+    let env = DefaultEnvironment::new(());
+    let mut buffer = FixedTextBuffer::<2, 4>::default();
+    let layout = hstack.layout(buffer.size(), &env);
+    hstack.render(&mut buffer, &layout, Point::zero(), &env);
+    let rows = buffer.text.iter().map(|row| row.iter().collect()).collect();
+    (layout.resolved_size, rows)
+}
+
+#[test]
+fn test_first_text_baseline_alignment() {
+    let (size, rows) = render_baselines(VerticalAlignment::FirstTextBaseline);
+    assert_eq!(size, Size::new(2, 3));
+    assert_eq!(rows, ["  ", "Xa", " b", "  "]);
+}
+
+#[test]
+fn test_last_text_baseline_alignment() {
+    let (size, rows) = render_baselines(VerticalAlignment::LastTextBaseline);
+    assert_eq!(size, Size::new(2, 3));
+    assert_eq!(rows, [" a", "Xb", "  ", "  "]);
+}
+
+#[test]
+fn test_baseline_alignment_grows_stack() {
+    let hstack = HStack::new((
+        Text::str("X", &TallFont),
+        Text::str("a\nb\nc", &BufferCharacterFont),
+    ))
+    .with_alignment(VerticalAlignment::FirstTextBaseline);
+    let env = DefaultEnvironment::new(());
+    let layout = hstack.layout(Size::new(10, 10), &env);
+    // The second text starts one row down to meet the taller font's baseline
+    assert_eq!(layout.resolved_size, Size::new(2, 4));
+}
+
+#[test]
+fn test_baseline_alignment_falls_back_to_bottom_edge() {
+    // The frame hides the baseline of its text, so the frame's bottom edge is used instead
+    let framed = Text::str("o", &BufferCharacterFont).frame(
+        Some(1),
+        Some(2),
+        None,
+        Some(VerticalAlignment::Top),
+    );
+    let hstack = HStack::new((Text::str("X", &TallFont), framed))
+        .with_alignment(VerticalAlignment::FirstTextBaseline);
+    let env = DefaultEnvironment::new(());
+    let mut buffer = FixedTextBuffer::<2, 3>::default();
+    let layout = hstack.layout(buffer.size(), &env);
+    hstack.render(&mut buffer, &layout, Point::zero(), &env);
+    let rows: Vec<String> = buffer.text.iter().map(|row| row.iter().collect()).collect();
+    assert_eq!(rows, [" o", "X ", "  "]);
+}
+
+#[test]
+fn test_baseline_alignment_sees_through_when() {
+    let padded = Text::str("a", &BufferCharacterFont).when(true, |v| v.padding(2));
+    let hstack = HStack::new((Text::str("X", &TallFont), padded))
+        .with_alignment(VerticalAlignment::FirstTextBaseline)
+        .with_spacing(0);
+    let env = DefaultEnvironment::new(());
+    let mut buffer = FixedTextBuffer::<16, 5>::default();
+    let layout = hstack.layout(buffer.size(), &env);
+    hstack.render(&mut buffer, &layout, Point::zero(), &env);
+    let rows: Vec<String> = buffer.text.iter().map(|row| row.iter().collect()).collect();
+    // The padded text's baseline is one row lower, so the taller font moves down to meet it
+    assert_eq!(rows[1].trim(), "");
+    assert_eq!(rows[2].trim_end(), "X  a");
+}
+
+#[test]
+fn overflow_behavior_with_oversized_children() {
+    let font = BufferCharacterFont {};
+    let env = DefaultEnvironment::new(());
+    for (overflow, expected) in [
+        (OverflowBehavior::Overflow, "aaaabbbb"),
+        (OverflowBehavior::ClipLast, "aaaabb  "),
+        (OverflowBehavior::Compress, "aaaabbbb"),
+    ] {
+        let hstack = HStack::new((
+            Text::str("aaaa", &font).frame(Some(4), Some(1), None, None),
+            Text::str("bbbb", &font).frame(Some(4), Some(1), None, None),
+        ))
+        .with_overflow_behavior(overflow);
+        let layout = hstack.layout(Size::new(6, 1), &env);
+        assert_eq!(layout.resolved_size, Size::new(6, 1));
+        assert_eq!(layout.sublayouts.1.resolved_size, Size::new(4, 1));
+
+        let mut buffer = FixedTextBuffer::<8, 1>::default();
+        hstack.render(&mut buffer, &layout, Point::zero(), &env);
+        assert_eq!(buffer.text[0].iter().collect::<String>(), expected);
+    }
+}
+
+/// Lowers the layout priority of a view below that of its siblings
+struct LowPriority<V>(V);
+
+impl<V: Layout> Layout for LowPriority<V> {
+    type Sublayout = V::Sublayout;
+
+    fn layout(
+        &self,
+        offer: Size,
+        env: &impl buoyant::environment::LayoutEnvironment,
+    ) -> buoyant::layout::ResolvedLayout<Self::Sublayout> {
+        self.0.layout(offer, env)
+    }
+
+    fn priority(&self) -> i8 {
+        -1
+    }
+}
+
+#[test]
+fn lower_priority_views_reserve_their_minimum_width() {
+    let font = BufferCharacterFont {};
+    let env = DefaultEnvironment::new(());
+
+    // A fixed frame can't shrink, so its width is held back from the higher priority text
+    let hstack = HStack::new((
+        Text::str("abcdef", &font),
+        LowPriority(Rectangle.frame(Some(3), Some(1), None, None)),
+    ));
+    let layout = hstack.layout(Size::new(6, 2), &env);
+    assert_eq!(layout.sublayouts.0.resolved_size, Size::new(3, 2));
+    assert_eq!(layout.sublayouts.1.resolved_size, Size::new(3, 1));
+
+    // Text is zero wide when offered zero width, so nothing is held back for it
+    let hstack = HStack::new((
+        Text::str("abcdef", &font),
+        LowPriority(Text::str("xyz", &font)),
+    ));
+    let layout = hstack.layout(Size::new(6, 2), &env);
+    assert_eq!(layout.sublayouts.0.resolved_size, Size::new(6, 1));
+    assert_eq!(layout.sublayouts.1.resolved_size.width, 0);
+}
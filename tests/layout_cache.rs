@@ -0,0 +1,206 @@
+use core::cell::Cell;
+
+use buoyant::{
+    environment::{DefaultEnvironment, LayoutEnvironment},
+    layout::{Layout, LayoutDirection, ResolvedLayout},
+    primitives::Size,
+    view::{LayoutExtensions as _, Rectangle, Spacer, Text, VStack},
+};
+use common::{TestEnv, TracedFont};
+
+mod common;
+
+/// Counts the number of times the wrapped view is laid out
+struct LayoutCounter<'a, V> {
+    count: &'a Cell<usize>,
+    child: V,
+}
+
+impl<V: Layout> Layout for LayoutCounter<'_, V> {
+    type Sublayout = V::Sublayout;
+
+    fn layout(&self, offer: Size, env: &impl LayoutEnvironment) -> ResolvedLayout<Self::Sublayout> {
+        self.count.set(self.count.get() + 1);
+        self.child.layout(offer, env)
+    }
+}
+
+#[test]
+fn identical_offers_lay_out_child_once() {
+    let count = Cell::new(0);
+    let view = LayoutCounter {
+        count: &count,
+        child: Rectangle.frame(Some(3), Some(2), None, None),
+    }
+    .layout_cache();
+    let env = DefaultEnvironment::new(());
+
+    for _ in 0..3 {
+        let layout = view.layout(Size::new(10, 10), &env);
+        assert_eq!(layout.resolved_size, Size::new(3, 2));
+    }
+    assert_eq!(count.get(), 1);
+}
+
+#[test]
+fn changed_offer_invalidates_cache() {
+    let count = Cell::new(0);
+    let view = LayoutCounter {
+        count: &count,
+        child: Rectangle,
+    }
+    .layout_cache();
+    let env = DefaultEnvironment::new(());
+
+    assert_eq!(
+        view.layout(Size::new(10, 10), &env).resolved_size,
+        Size::new(10, 10)
+    );
+    assert_eq!(
+        view.layout(Size::new(5, 10), &env).resolved_size,
+        Size::new(5, 10)
+    );
+    assert_eq!(
+        view.layout(Size::new(5, 10), &env).resolved_size,
+        Size::new(5, 10)
+    );
+    assert_eq!(count.get(), 2);
+}
+
+#[test]
+fn changed_layout_direction_invalidates_cache() {
+    let count = Cell::new(0);
+    let view = LayoutCounter {
+        count: &count,
+        child: Spacer::default(),
+    }
+    .layout_cache();
+    let horizontal = TestEnv::colorless().with_direction(LayoutDirection::Horizontal);
+    let vertical = TestEnv::colorless().with_direction(LayoutDirection::Vertical);
+
+    let offer = Size::new(4, 6);
+    assert_eq!(
+        view.layout(offer, &horizontal).resolved_size,
+        Size::new(4, 0)
+    );
+    assert_eq!(view.layout(offer, &vertical).resolved_size, Size::new(0, 6));
+    assert_eq!(count.get(), 2);
+}
+
+#[test]
+fn cached_child_is_laid_out_once_across_frames() {
+    let count = Cell::new(0);
+    let stack = VStack::new((
+        LayoutCounter {
+            count: &count,
+            child: Rectangle.frame(Some(2), Some(2), None, None),
+        }
+        .layout_cache(),
+        Spacer::default(),
+    ));
+    let env = DefaultEnvironment::new(());
+
+    let first = stack.layout(Size::new(6, 6), &env);
+    let after_first_frame = count.get();
+    for _ in 0..3 {
+        let layout = stack.layout(Size::new(6, 6), &env);
+        assert_eq!(layout.resolved_size, first.resolved_size);
+    }
+    assert_eq!(count.get(), after_first_frame);
+}
+
+/// A view as wide as the shared width, which can change while the view is kept between frames
+struct SharedWidth<'a> {
+    width: &'a Cell<u16>,
+}
+
+impl Layout for SharedWidth<'_> {
+    type Sublayout = ();
+
+    fn layout(&self, _: Size, _: &impl LayoutEnvironment) -> ResolvedLayout<()> {
+        ResolvedLayout {
+            sublayouts: (),
+            resolved_size: Size::new(self.width.get(), 1),
+        }
+    }
+}
+
+#[test]
+fn clear_discards_stale_layouts() {
+    let width = Cell::new(2);
+    let view = SharedWidth { width: &width }.layout_cache();
+    let env = DefaultEnvironment::new(());
+    let offer = Size::new(10, 10);
+    assert_eq!(view.layout(offer, &env).resolved_size, Size::new(2, 1));
+
+    width.set(5);
+    // The content changed without the view being rebuilt, so the cached layout is stale
+    assert_eq!(view.layout(offer, &env).resolved_size, Size::new(2, 1));
+    view.clear();
+    assert_eq!(view.layout(offer, &env).resolved_size, Size::new(5, 1));
+}
+
+struct Width(u16);
+
+/// A view as wide as the width in the environment
+struct EnvironmentWidth;
+
+impl Layout for EnvironmentWidth {
+    type Sublayout = ();
+
+    fn layout(&self, _: Size, env: &impl LayoutEnvironment) -> ResolvedLayout<()> {
+        let width = env.value::<Width>().map_or(1, |w| w.0);
+        ResolvedLayout {
+            sublayouts: (),
+            resolved_size: Size::new(width, 1),
+        }
+    }
+}
+
+/// Lays out a borrowed view, so one cache can be placed in several environments
+struct Borrowed<'a, V>(&'a V);
+
+impl<V: Layout> Layout for Borrowed<'_, V> {
+    type Sublayout = V::Sublayout;
+
+    fn layout(&self, offer: Size, env: &impl LayoutEnvironment) -> ResolvedLayout<Self::Sublayout> {
+        self.0.layout(offer, env)
+    }
+}
+
+#[test]
+fn environment_values_are_not_part_of_the_key() {
+    let view = EnvironmentWidth.layout_cache();
+    let env = DefaultEnvironment::new(());
+    let offer = Size::new(10, 10);
+    let layout_with = |width| {
+        Borrowed(&view)
+            .environment(Width(width))
+            .layout(offer, &env)
+            .resolved_size
+    };
+    assert_eq!(layout_with(2), Size::new(2, 1));
+
+    // The cached layout is stale until the cache is cleared
+    assert_eq!(layout_with(5), Size::new(2, 1));
+    view.clear();
+    assert_eq!(layout_with(5), Size::new(5, 1));
+}
+
+#[test]
+fn repeated_text_layout_does_not_remeasure() {
+    let font = TracedFont::default();
+    let view = Text::str("hello world", &font).layout_cache();
+    let env = DefaultEnvironment::new(());
+
+    let first = view.layout(Size::new(6, 4), &env);
+    let measured = font.measurements.get();
+    assert!(measured > 0);
+
+    let second = view.layout(Size::new(6, 4), &env);
+    assert_eq!(first.resolved_size, second.resolved_size);
+    assert_eq!(font.measurements.get(), measured);
+
+    view.layout(Size::new(20, 4), &env);
+    assert!(font.measurements.get() > measured);
+}
@@ -0,0 +1,101 @@
+use buoyant::{
+    environment::DefaultEnvironment,
+    font::BufferCharacterFont,
+    layout::{HorizontalAlignment, Layout as _},
+    primitives::{Point, Size},
+    render::CharacterRender,
+    render_target::{CharacterRenderTarget, FixedTextBuffer},
+    view::{ForEach, Section, Text, VStack},
+};
+
+static FONT: BufferCharacterFont = BufferCharacterFont {};
+
+#[test]
+fn sections_interleave_headers_rows_and_footers() {
+    let network = ["Wi-Fi", "VPN"];
+    let display = ["Dark"];
+    let view = VStack::new((
+        Section::new(
+            ForEach::<4, _, _, _>::new(&network, |row| Text::str(row, &FONT))
+                .with_alignment(HorizontalAlignment::Leading),
+        )
+        .header(Text::str("NETWORK", &FONT))
+        .footer(Text::str("2 items", &FONT))
+        .with_alignment(HorizontalAlignment::Leading),
+        Section::new(
+            ForEach::<4, _, _, _>::new(&display, |row| Text::str(row, &FONT))
+                .with_alignment(HorizontalAlignment::Leading),
+        )
+        .header(Text::str("DISPLAY", &FONT))
+        .with_alignment(HorizontalAlignment::Leading),
+    ))
+    .with_alignment(HorizontalAlignment::Leading);
+
+    let env = DefaultEnvironment::new(());
+    let mut buffer = FixedTextBuffer::<8, 8>::default();
+    let layout = view.layout(buffer.size(), &env);
+    assert_eq!(layout.resolved_size, Size::new(7, 6));
+    assert_eq!(layout.sublayouts.0.resolved_size, Size::new(7, 4));
+    assert_eq!(layout.sublayouts.1.resolved_size, Size::new(7, 2));
+
+    view.render(&mut buffer, &layout, Point::zero(), &env);
+
+    let lines = [
+        "NETWORK ", "Wi-Fi   ", "VPN     ", "2 items ", "DISPLAY ", "Dark    ", "        ",
+        "        ",
+    ];
+    for (expected, actual) in lines.iter().zip(buffer.text.iter()) {
+        assert_eq!(actual.iter().collect::<String>(), *expected);
+    }
+}
+
+#[test]
+fn section_without_header_or_footer_hugs_content() {
+    let rows = ["a", "b"];
+    let view = Section::new(ForEach::<4, _, _, _>::new(&rows, |row| {
+        Text::str(row, &FONT)
+    }));
+    let env = DefaultEnvironment::new(());
+    let layout = view.layout(Size::new(5, 5), &env);
+    assert_eq!(layout.resolved_size, Size::new(1, 2));
+}
+
+#[test]
+fn section_spacing_survives_header_and_footer() {
+    let rows = ["a", "b"];
+    let view = Section::new(ForEach::<4, _, _, _>::new(&rows, |row| {
+        Text::str(row, &FONT)
+    }))
+    .with_spacing(1)
+    .header(Text::str("H", &FONT))
+    .footer(Text::str("F", &FONT));
+    let env = DefaultEnvironment::new(());
+    let mut buffer = FixedTextBuffer::<1, 6>::default();
+    let layout = view.layout(buffer.size(), &env);
+    assert_eq!(layout.resolved_size, Size::new(1, 6));
+
+    view.render(&mut buffer, &layout, Point::zero(), &env);
+    let column: String = buffer.text.iter().map(|row| row[0]).collect();
+    assert_eq!(column, "H ab F");
+}
+
+#[test]
+fn section_spacing_skips_missing_header_and_footer() {
+    let rows = ["a", "b"];
+    let view = Section::new(ForEach::<4, _, _, _>::new(&rows, |row| {
+        Text::str(row, &FONT)
+    }))
+    .with_spacing(1);
+    let env = DefaultEnvironment::new(());
+    let layout = view.layout(Size::new(5, 5), &env);
+    assert_eq!(layout.resolved_size, Size::new(1, 2));
+
+    let view = view.header(Text::str("H", &FONT));
+    let mut buffer = FixedTextBuffer::<1, 5>::default();
+    let layout = view.layout(buffer.size(), &env);
+    assert_eq!(layout.resolved_size, Size::new(1, 4));
+
+    view.render(&mut buffer, &layout, Point::zero(), &env);
+    let column: String = buffer.text.iter().map(|row| row[0]).collect();
+    assert_eq!(column, "H ab ");
+}
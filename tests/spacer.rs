@@ -3,7 +3,7 @@ use buoyant::layout::{Layout, LayoutDirection};
 use buoyant::primitives::{Point, Size};
 use buoyant::render::CharacterRender;
 use buoyant::render_target::{CharacterRenderTarget, FixedTextBuffer};
-use buoyant::view::{HStack, Spacer, Text};
+use buoyant::view::{HStack, Spacer, Text, VStack};
 use common::TestEnv;
 
 mod common;
@@ -36,3 +36,41 @@ fn test_render_fills_stack() {
     hstack.render(&mut buffer, &layout, Point::zero(), &env);
     assert_eq!(buffer.text[0].iter().collect::<String>(), "       67");
 }
+
+#[test]
+fn test_min_length_is_reserved_in_hstack() {
+    let font = BufferCharacterFont {};
+    let hstack = HStack::new((
+        Text::str("abcdef", &font),
+        Spacer::new().min_length(4),
+        Text::str("|", &font),
+    ));
+    let mut buffer = FixedTextBuffer::<8, 3>::default();
+    let env = TestEnv::default();
+    let layout = hstack.layout(buffer.size(), &env);
+    assert_eq!(layout.resolved_size, Size::new(8, 2));
+    assert_eq!(layout.sublayouts.1.resolved_size.width, 4);
+    hstack.render(&mut buffer, &layout, Point::zero(), &env);
+    assert_eq!(buffer.text[0].iter().collect::<String>(), "abc    |");
+    assert_eq!(buffer.text[1].iter().collect::<String>(), "def     ");
+}
+
+#[test]
+fn test_min_length_is_reserved_in_vstack() {
+    let font = BufferCharacterFont {};
+    let vstack = VStack::new((Text::str("a b c d", &font), Spacer::new().min_length(2)));
+    let env = TestEnv::colorless();
+    let layout = vstack.layout(Size::new(1, 4), &env);
+    assert_eq!(layout.sublayouts.0.resolved_size.height, 2);
+    assert_eq!(layout.sublayouts.1.resolved_size.height, 2);
+}
+
+#[test]
+fn test_spacer_without_min_length_collapses() {
+    let font = BufferCharacterFont {};
+    let hstack = HStack::new((Text::str("abcdefgh", &font), Spacer::new()));
+    let env = TestEnv::colorless();
+    let layout = hstack.layout(Size::new(8, 1), &env);
+    assert_eq!(layout.sublayouts.0.resolved_size.width, 8);
+    assert_eq!(layout.sublayouts.1.resolved_size.width, 0);
+}
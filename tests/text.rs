@@ -3,11 +3,11 @@ use std::iter::zip;
 use buoyant::{
     environment::DefaultEnvironment,
     font::{BufferCharacterFont, CharacterFont, FontLayout},
-    layout::Layout as _,
+    layout::{Layout as _, VerticalAlignment},
     primitives::{Point, Size},
     render::CharacterRender,
     render_target::{CharacterRenderTarget, FixedTextBuffer},
-    view::{HorizontalTextAlignment, LayoutExtensions as _, Text},
+    view::{HorizontalTextAlignment, LayoutExtensions as _, Text, TruncationMode},
 };
 
 #[derive(Debug)]
@@ -231,3 +231,302 @@ fn test_clipped_text_trails_correctly() {
         assert_eq!(actual.iter().collect::<String>(), *expected);
     });
 }
+
+#[test]
+fn test_vertical_text_alignment_center_in_tall_frame() {
+    let font = BufferCharacterFont {};
+    let text = Text::str("hi", &font)
+        .vertical_text_alignment(VerticalAlignment::Center)
+        .frame(Some(4), Some(5), None, Some(VerticalAlignment::Top));
+
+    let env = DefaultEnvironment::new(());
+    let mut buffer = FixedTextBuffer::<4, 5>::default();
+
+    let layout = text.layout(buffer.size(), &env);
+    assert_eq!(layout.resolved_size, Size::new(4, 5));
+    assert_eq!(layout.sublayouts.resolved_size, Size::new(2, 5));
+
+    text.render(&mut buffer, &layout, Point::zero(), &env);
+
+    let lines = ["    ", "    ", " hi ", "    ", "    "];
+    zip(lines.iter(), buffer.text.iter()).for_each(|(expected, actual)| {
+        assert_eq!(actual.iter().collect::<String>(), *expected);
+    });
+}
+
+#[test]
+fn test_vertical_text_alignment_bottom() {
+    let font = BufferCharacterFont {};
+    let text = Text::str("two\nlines", &font).vertical_text_alignment(VerticalAlignment::Bottom);
+
+    let env = DefaultEnvironment::new(());
+    let mut buffer = FixedTextBuffer::<5, 4>::default();
+
+    let layout = text.layout(buffer.size(), &env);
+    assert_eq!(layout.resolved_size, Size::new(5, 4));
+
+    text.render(&mut buffer, &layout, Point::zero(), &env);
+
+    let lines = ["     ", "     ", "two  ", "lines"];
+    zip(lines.iter(), buffer.text.iter()).for_each(|(expected, actual)| {
+        assert_eq!(actual.iter().collect::<String>(), *expected);
+    });
+}
+
+#[test]
+fn test_text_without_vertical_alignment_hugs_content() {
+    let font = BufferCharacterFont {};
+    let text = Text::str("hi", &font);
+    let env = DefaultEnvironment::new(());
+    let layout = text.layout(Size::new(4, 5), &env);
+    assert_eq!(layout.resolved_size, Size::new(2, 1));
+}
+
+#[test]
+fn test_vertical_text_bounds_are_transposed() {
+    let font = BufferCharacterFont {};
+    let env = DefaultEnvironment::new(());
+    let offer = Size::new(10, 10);
+
+    let horizontal = Text::str("abc", &font).layout(offer, &env);
+    let vertical = Text::str("abc", &font).vertical().layout(offer, &env);
+    assert_eq!(horizontal.resolved_size, Size::new(3, 1));
+    assert_eq!(vertical.resolved_size, Size::new(1, 3));
+}
+
+#[test]
+fn test_vertical_text_drops_columns_wider_than_offer() {
+    let font = ArbitraryFont {
+        line_height: 1,
+        character_width: 3,
+    };
+    let env = DefaultEnvironment::new(());
+    let layout = Text::str("ab cd", &font)
+        .vertical()
+        .layout(Size::new(5, 2), &env);
+    assert_eq!(layout.resolved_size, Size::new(3, 2));
+}
+
+#[test]
+fn test_vertical_text_advances_by_line_height() {
+    let font = ArbitraryFont {
+        line_height: 10,
+        character_width: 5,
+    };
+    let env = DefaultEnvironment::new(());
+    let layout = Text::str("abcd", &font)
+        .vertical()
+        .layout(Size::new(100, 20), &env);
+    assert_eq!(layout.resolved_size, Size::new(10, 20));
+}
+
+#[test]
+fn test_render_vertical_text_wraps_into_columns() {
+    let font = BufferCharacterFont {};
+    let env = DefaultEnvironment::new(());
+    let mut buffer = FixedTextBuffer::<3, 3>::default();
+    let text = Text::str("abc de", &font)
+        .multiline_text_alignment(HorizontalTextAlignment::Trailing)
+        .vertical();
+    let layout = text.layout(buffer.size(), &env);
+    assert_eq!(layout.resolved_size, Size::new(2, 3));
+
+    text.render(&mut buffer, &layout, Point::zero(), &env);
+
+    let lines = ["a  ", "bd ", "ce "];
+    zip(lines.iter(), buffer.text.iter()).for_each(|(expected, actual)| {
+        assert_eq!(actual.iter().collect::<String>(), *expected);
+    });
+}
+
+#[test]
+fn test_render_tail_truncation() {
+    let font = BufferCharacterFont {};
+    let env = DefaultEnvironment::new(());
+    let mut buffer = FixedTextBuffer::<6, 1>::default();
+    let text = Text::str("hello world", &font).truncation_mode(TruncationMode::Tail);
+    let layout = text.layout(buffer.size(), &env);
+    assert_eq!(layout.resolved_size, Size::new(6, 1));
+
+    text.render(&mut buffer, &layout, Point::zero(), &env);
+
+    assert_eq!(buffer.text[0].iter().collect::<String>(), "hello…");
+}
+
+#[test]
+fn test_render_truncation_on_last_visible_line() {
+    let font = BufferCharacterFont {};
+    let env = DefaultEnvironment::new(());
+    let mut buffer = FixedTextBuffer::<5, 2>::default();
+    let text = Text::str("one two three", &font).truncation_mode(TruncationMode::Head);
+    let layout = text.layout(buffer.size(), &env);
+    text.render(&mut buffer, &layout, Point::zero(), &env);
+
+    let lines = ["one  ", "…hree"];
+    zip(lines.iter(), buffer.text.iter()).for_each(|(expected, actual)| {
+        assert_eq!(actual.iter().collect::<String>(), *expected);
+    });
+}
+
+#[test]
+fn test_letter_spacing_is_between_characters() {
+    let font = BufferCharacterFont {};
+    let env = DefaultEnvironment::new(());
+    let mut buffer = FixedTextBuffer::<6, 1>::default();
+    let text = Text::str("abc", &font).letter_spacing(1);
+    let layout = text.layout(buffer.size(), &env);
+    assert_eq!(layout.resolved_size, Size::new(5, 1));
+
+    text.render(&mut buffer, &layout, Point::zero(), &env);
+
+    assert_eq!(buffer.text[0].iter().collect::<String>(), "a b c ");
+}
+
+#[test]
+fn test_letter_spacing_affects_wrapping() {
+    let font = BufferCharacterFont {};
+    let env = DefaultEnvironment::new(());
+    let mut buffer = FixedTextBuffer::<4, 2>::default();
+    let text = Text::str("ab cd", &font).letter_spacing(1);
+    let layout = text.layout(buffer.size(), &env);
+    assert_eq!(layout.resolved_size, Size::new(3, 2));
+
+    text.render(&mut buffer, &layout, Point::zero(), &env);
+
+    let lines = ["a b ", "c d "];
+    zip(lines.iter(), buffer.text.iter()).for_each(|(expected, actual)| {
+        assert_eq!(actual.iter().collect::<String>(), *expected);
+    });
+}
+
+#[test]
+fn test_negative_letter_spacing_is_clamped() {
+    let font = ArbitraryFont {
+        line_height: 10,
+        character_width: 3,
+    };
+    let env = DefaultEnvironment::new(());
+    let tight = Text::str("abc", &font).letter_spacing(-1);
+    assert_eq!(
+        tight.layout(Size::new(100, 100), &env).resolved_size,
+        Size::new(7, 10)
+    );
+    let overlapping = Text::str("abc", &font).letter_spacing(-5);
+    assert_eq!(
+        overlapping.layout(Size::new(100, 100), &env).resolved_size,
+        Size::new(3, 10)
+    );
+}
+
+#[test]
+fn test_line_spacing_is_between_lines() {
+    let font = BufferCharacterFont {};
+    let env = DefaultEnvironment::new(());
+    let text = Text::str("one\ntwo\nsix", &font).line_spacing(2);
+    let layout = text.layout(Size::new(10, 10), &env);
+    assert_eq!(layout.resolved_size, Size::new(3, 7));
+
+    let mut buffer = FixedTextBuffer::<3, 7>::default();
+    text.render(&mut buffer, &layout, Point::zero(), &env);
+
+    let lines = ["one", "   ", "   ", "two", "   ", "   ", "six"];
+    zip(lines.iter(), buffer.text.iter()).for_each(|(expected, actual)| {
+        assert_eq!(actual.iter().collect::<String>(), *expected);
+    });
+}
+
+#[test]
+fn test_negative_line_spacing_overlaps_lines() {
+    let font = ArbitraryFont {
+        line_height: 10,
+        character_width: 1,
+    };
+    let env = DefaultEnvironment::new(());
+    let tight = Text::str("a\nb\nc", &font).line_spacing(-4);
+    assert_eq!(
+        tight.layout(Size::new(10, 100), &env).resolved_size,
+        Size::new(1, 22)
+    );
+    let stacked = Text::str("a\nb\nc", &font).line_spacing(-20);
+    assert_eq!(
+        stacked.layout(Size::new(10, 100), &env).resolved_size,
+        Size::new(1, 10)
+    );
+}
+
+#[test]
+fn test_tabs_align_to_tab_stops() {
+    let font = BufferCharacterFont {};
+    let env = DefaultEnvironment::new(());
+    let mut buffer = FixedTextBuffer::<8, 2>::default();
+    let text = Text::str("a\tb\nabcde\tf", &font);
+    let layout = text.layout(buffer.size(), &env);
+    assert_eq!(layout.resolved_size, Size::new(5, 2));
+    text.render(&mut buffer, &layout, Point::zero(), &env);
+
+    let lines = ["a   b   ", "abcde   "];
+    zip(lines.iter(), buffer.text.iter()).for_each(|(expected, actual)| {
+        assert_eq!(actual.iter().collect::<String>(), *expected);
+    });
+}
+
+/// A proportional font where the digit one is narrower than the other characters
+struct ProportionalFont;
+
+impl FontLayout for ProportionalFont {
+    fn line_height(&self) -> u16 {
+        1
+    }
+    fn character_width(&self, character: char) -> u16 {
+        match character {
+            '1' => 1,
+            _ => 2,
+        }
+    }
+}
+
+impl CharacterFont<()> for ProportionalFont {
+    fn render_iter<T, I>(&self, target: &mut T, origin: Point, characters: I)
+    where
+        T: CharacterRenderTarget<Color = ()>,
+        I: IntoIterator<Item = (char, ())>,
+    {
+        let mut x = 0;
+        for (character, color) in characters {
+            target.draw(origin + Point::new(x, 0), character, color);
+            x += self.character_width(character) as i16;
+        }
+    }
+}
+
+#[test]
+fn test_monospaced_digits_share_a_width() {
+    let env = DefaultEnvironment::new(());
+    let offer = Size::new(100, 100);
+    let width = |text: Text<'_, &str, ProportionalFont>| text.layout(offer, &env).resolved_size;
+    assert_eq!(width(Text::str("1", &ProportionalFont)), Size::new(1, 1));
+    assert_eq!(width(Text::str("8", &ProportionalFont)), Size::new(2, 1));
+    assert_eq!(
+        width(Text::str("1", &ProportionalFont).monospaced_digits()),
+        Size::new(2, 1)
+    );
+    assert_eq!(
+        width(Text::str("8", &ProportionalFont).monospaced_digits()),
+        Size::new(2, 1)
+    );
+    // Other characters keep their natural widths
+    assert_eq!(
+        width(Text::str("a1a", &ProportionalFont).monospaced_digits()),
+        Size::new(6, 1)
+    );
+}
+
+#[test]
+fn test_monospaced_digits_render_in_fixed_columns() {
+    let env = DefaultEnvironment::new(());
+    let mut buffer = FixedTextBuffer::<6, 1>::default();
+    let text = Text::str("118", &ProportionalFont).monospaced_digits();
+    let layout = text.layout(buffer.size(), &env);
+    text.render(&mut buffer, &layout, Point::zero(), &env);
+    assert_eq!(buffer.text[0].iter().collect::<String>(), "1 1 8 ");
+}
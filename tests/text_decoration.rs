@@ -0,0 +1,63 @@
+#![cfg(feature = "embedded-graphics")]
+
+use buoyant::{
+    environment::DefaultEnvironment,
+    layout::HorizontalAlignment,
+    primitives::{Point, Size},
+    render::PixelRender,
+    view::{HorizontalTextAlignment, LayoutExtensions as _, Text},
+};
+use embedded_graphics::{
+    mock_display::MockDisplay,
+    mono_font::{ascii::FONT_6X10, MonoFont},
+    pixelcolor::BinaryColor,
+};
+
+static FONT: MonoFont = FONT_6X10;
+
+fn render(view: &impl PixelRender<BinaryColor>, size: Size) -> MockDisplay<BinaryColor> {
+    let env = DefaultEnvironment::new(BinaryColor::On);
+    let mut display = MockDisplay::new();
+    // Decorations cross the glyphs
+    display.set_allow_overdraw(true);
+    let layout = view.layout(size, &env);
+    view.render(&mut display, &layout, Point::zero(), &env);
+    display
+}
+
+fn row(display: &MockDisplay<BinaryColor>, y: i32, width: i32) -> Vec<bool> {
+    (0..width)
+        .map(|x| {
+            display.get_pixel(embedded_graphics::geometry::Point::new(x, y))
+                == Some(BinaryColor::On)
+        })
+        .collect()
+}
+
+#[test]
+fn underline_is_drawn_below_baseline() {
+    let display = render(&Text::str("ab", &FONT).underline(), Size::new(20, 10));
+    // The baseline of FONT_6X10 is at row 7
+    assert_eq!(row(&display, 8, 13), [vec![true; 12], vec![false]].concat());
+    assert!(row(&display, 5, 13).iter().any(|on| !on));
+}
+
+#[test]
+fn strikethrough_is_drawn_at_mid_height() {
+    let display = render(&Text::str("ab", &FONT).strikethrough(), Size::new(20, 10));
+    assert_eq!(row(&display, 5, 13), [vec![true; 12], vec![false]].concat());
+    assert_eq!(row(&display, 8, 13), vec![false; 13]);
+}
+
+#[test]
+fn decoration_follows_line_alignment() {
+    let view = Text::str("ab cdef", &FONT)
+        .multiline_text_alignment(HorizontalTextAlignment::Center)
+        .underline()
+        .frame(Some(24), None, Some(HorizontalAlignment::Leading), None);
+    let display = render(&view, Size::new(24, 20));
+    // "ab" is centered on the first line, "cdef" fills the second
+    let first = [vec![false; 6], vec![true; 12], vec![false; 6]].concat();
+    assert_eq!(row(&display, 8, 24), first);
+    assert_eq!(row(&display, 18, 24), vec![true; 24]);
+}
@@ -0,0 +1,108 @@
+use buoyant::{
+    environment::DefaultEnvironment, font::BufferCharacterFont, layout::Layout as _,
+    primitives::Point, render::CharacterRender as _, render_target::CharacterRenderTarget as _,
+    view::Text,
+};
+use common::ColorBuffer;
+
+mod common;
+
+static FONT: BufferCharacterFont = BufferCharacterFont {};
+
+#[test]
+fn highlight_spans_wrapped_lines() {
+    let view = Text::str("abc def", &FONT).highlight(2..5, 'h');
+    let env = DefaultEnvironment::new('f');
+    let mut buffer = ColorBuffer::<char, 4, 2>::default();
+    let layout = view.layout(buffer.size(), &env);
+    view.render(&mut buffer, &layout, Point::zero(), &env);
+
+    assert_eq!(buffer.text, [['a', 'b', 'c', ' '], ['d', 'e', 'f', ' ']]);
+    assert_eq!(
+        buffer.colors,
+        [
+            [Some('f'), Some('f'), Some('h'), None],
+            [Some('h'), Some('f'), Some('f'), None],
+        ]
+    );
+}
+
+#[test]
+fn highlight_widens_to_char_boundaries() {
+    // The range starts inside the two byte 'é'
+    let view = Text::str("héllo", &FONT).highlight(2..4, 'h');
+    let env = DefaultEnvironment::new('f');
+    let mut buffer = ColorBuffer::<char, 5, 1>::default();
+    let layout = view.layout(buffer.size(), &env);
+    view.render(&mut buffer, &layout, Point::zero(), &env);
+
+    assert_eq!(buffer.text, [['h', 'é', 'l', 'l', 'o']]);
+    assert_eq!(
+        buffer.colors,
+        [[Some('f'), Some('h'), Some('h'), Some('f'), Some('f')]]
+    );
+}
+
+#[test]
+fn empty_highlight_draws_plain_text() {
+    let view = Text::str("abc", &FONT).highlight(1..1, 'h');
+    let env = DefaultEnvironment::new('f');
+    let mut buffer = ColorBuffer::<char, 3, 1>::default();
+    let layout = view.layout(buffer.size(), &env);
+    view.render(&mut buffer, &layout, Point::zero(), &env);
+
+    assert_eq!(buffer.colors, [[Some('f'); 3]]);
+}
+
+#[cfg(feature = "embedded-graphics")]
+mod pixel {
+    use buoyant::{
+        environment::DefaultEnvironment,
+        layout::Layout as _,
+        primitives::{Point, Size},
+        render::PixelRender as _,
+        view::Text,
+    };
+    use embedded_graphics::{
+        mock_display::MockDisplay,
+        mono_font::{ascii::FONT_6X10, MonoFont},
+        pixelcolor::Rgb565,
+        prelude::RgbColor as _,
+    };
+
+    static FONT: MonoFont = FONT_6X10;
+
+    /// The colors of the pixels in the cell of a 6x10 glyph
+    fn cell(display: &MockDisplay<Rgb565>, column: i32, row: i32) -> Vec<Option<Rgb565>> {
+        (0..6)
+            .flat_map(|x| (0..10).map(move |y| (x, y)))
+            .map(|(x, y)| {
+                let point = embedded_graphics::geometry::Point::new(column * 6 + x, row * 10 + y);
+                display.get_pixel(point)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn highlight_fills_background_across_wrapped_lines() {
+        let view = Text::str("abc def", &FONT).highlight(2..5, Rgb565::YELLOW);
+        let env = DefaultEnvironment::new(Rgb565::BLACK);
+        let mut display = MockDisplay::new();
+        // Glyphs are drawn over the highlight
+        display.set_allow_overdraw(true);
+        let layout = view.layout(Size::new(24, 20), &env);
+        view.render(&mut display, &layout, Point::zero(), &env);
+
+        // "abc" on the first line and "def" on the second, highlighting 'c' and 'd'
+        for (column, row) in [(2, 0), (0, 1)] {
+            let pixels = cell(&display, column, row);
+            // Every pixel is filled, either by the highlight or by the glyph over it
+            assert!(pixels.iter().all(Option::is_some));
+            assert!(pixels.contains(&Some(Rgb565::YELLOW)));
+            assert!(pixels.contains(&Some(Rgb565::BLACK)));
+        }
+        for (column, row) in [(0, 0), (1, 0), (3, 0), (1, 1), (2, 1), (3, 1)] {
+            assert!(!cell(&display, column, row).contains(&Some(Rgb565::YELLOW)));
+        }
+    }
+}
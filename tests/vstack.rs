@@ -1,6 +1,6 @@
 use buoyant::environment::DefaultEnvironment;
 use buoyant::font::BufferCharacterFont;
-use buoyant::layout::{HorizontalAlignment, Layout, VerticalAlignment};
+use buoyant::layout::{HorizontalAlignment, Layout, OverflowBehavior, VerticalAlignment};
 use buoyant::primitives::{Point, Size};
 use buoyant::render::CharacterRender;
 use buoyant::render_target::{CharacterRenderTarget as _, FixedTextBuffer};
@@ -375,3 +375,62 @@ fn test_layout_3_extra_space_allocation() {
     // multiline text alignment applies within the frame of the text
     // the leading c is correct
 }
+
+#[test]
+fn overflow_behavior_with_oversized_children() {
+    let font = BufferCharacterFont {};
+    let env = DefaultEnvironment::new(());
+    for (overflow, expected) in [
+        (OverflowBehavior::Overflow, "aabbbb"),
+        (OverflowBehavior::ClipLast, "aabb  "),
+        (OverflowBehavior::Compress, "aabbbb"),
+    ] {
+        let vstack = VStack::new((
+            Text::str("a\na", &font).frame(Some(1), Some(2), None, None),
+            Text::str("b\nb\nb\nb", &font).frame(Some(1), Some(4), None, None),
+        ))
+        .with_overflow_behavior(overflow);
+        let layout = vstack.layout(Size::new(1, 4), &env);
+        assert_eq!(layout.resolved_size, Size::new(1, 4));
+        assert_eq!(layout.sublayouts.1.resolved_size, Size::new(1, 4));
+
+        let mut buffer = FixedTextBuffer::<1, 6>::default();
+        vstack.render(&mut buffer, &layout, Point::zero(), &env);
+        let column: String = buffer.text.iter().map(|row| row[0]).collect();
+        assert_eq!(column, expected);
+    }
+}
+
+/// Lowers the layout priority of a view below that of its siblings
+struct LowPriority<V>(V);
+
+impl<V: Layout> Layout for LowPriority<V> {
+    type Sublayout = V::Sublayout;
+
+    fn layout(
+        &self,
+        offer: Size,
+        env: &impl buoyant::environment::LayoutEnvironment,
+    ) -> buoyant::layout::ResolvedLayout<Self::Sublayout> {
+        self.0.layout(offer, env)
+    }
+
+    fn priority(&self) -> i8 {
+        -1
+    }
+}
+
+#[test]
+fn lower_priority_views_reserve_their_minimum_height() {
+    let font = BufferCharacterFont {};
+    let env = DefaultEnvironment::new(());
+
+    // A fixed frame can't shrink, so its height is held back from the higher priority text
+    let vstack = VStack::new((
+        Text::str("a b c d", &font),
+        LowPriority(Rectangle.frame(Some(1), Some(3), None, None)),
+    ));
+    let layout = vstack.layout(Size::new(1, 4), &env);
+    assert_eq!(layout.sublayouts.0.resolved_size, Size::new(1, 1));
+    assert_eq!(layout.sublayouts.1.resolved_size, Size::new(1, 3));
+}